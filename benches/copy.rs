@@ -0,0 +1,30 @@
+use bitptr::{ BitPtr, BitPtrMut };
+use criterion::{ criterion_group, criterion_main, BenchmarkId, Criterion, Throughput };
+use std::hint::black_box;
+
+
+const BYTES : usize = 4096;
+
+
+fn copy_nonoverlapping(c : &mut Criterion) {
+    let     src = (0..(BYTES + 1)).map(|i| (i as u8).wrapping_mul(0x9D)).collect::<Vec<_>>();
+    let mut dst = vec![0u8; BYTES + 1];
+    let bit_count = BYTES * 8;
+
+    let mut group = c.benchmark_group("copy_nonoverlapping");
+    group.throughput(Throughput::Bytes(BYTES as u64));
+    for src_bit in 0..8 {
+        for dst_bit in 0..8 {
+            group.bench_function(BenchmarkId::from_parameter(format!("{src_bit}->{dst_bit}")), |b| {
+                let srcptr = unsafe { BitPtr::new_with_offset(src.as_ptr(), src_bit) };
+                let dstptr = unsafe { BitPtrMut::new_with_offset(dst.as_mut_ptr(), dst_bit) };
+                b.iter(|| unsafe { bitptr::copy_nonoverlapping(black_box(srcptr), black_box(dstptr), black_box(bit_count)) });
+            });
+        }
+    }
+    group.finish();
+}
+
+
+criterion_group!(benches, copy_nonoverlapping);
+criterion_main!(benches);
@@ -0,0 +1,447 @@
+use crate::{ BitPtrMut, BitOrder, Msb0 };
+use crate::order::reorder;
+use core::marker::PhantomData;
+use core::fmt;
+use core::sync::atomic::Ordering;
+
+#[cfg(target_has_atomic = "8")]
+use core::sync::atomic::AtomicU8;
+#[cfg(all(target_has_atomic = "ptr", not(all(test, loom))))]
+use core::sync::atomic::AtomicPtr;
+#[cfg(all(target_has_atomic = "ptr", test, loom))]
+use loom::sync::atomic::AtomicPtr;
+
+
+/// The atomic operations on a byte which [`compare_exchange_bit`] is built from.
+#[cfg(target_has_atomic = "8")]
+pub(crate) trait AtomicByte {
+    fn load(&self, order : Ordering) -> u8;
+    fn compare_exchange_weak(&self, current : u8, new : u8, success : Ordering, failure : Ordering) -> Result<u8, u8>;
+}
+
+macro_rules! impl_atomic_byte { ( $ty:ty ) => {
+    impl AtomicByte for $ty {
+        #[inline(always)]
+        fn load(&self, order : Ordering) -> u8 { self.load(order) }
+        #[inline(always)]
+        fn compare_exchange_weak(&self, current : u8, new : u8, success : Ordering, failure : Ordering) -> Result<u8, u8> {
+            self.compare_exchange_weak(current, new, success, failure)
+        }
+    }
+} }
+
+#[cfg(target_has_atomic = "8")]
+impl_atomic_byte!(AtomicU8);
+#[cfg(all(test, loom))]
+impl_atomic_byte!(loom::sync::atomic::AtomicU8);
+
+
+/// Replaces the bit of `byte` selected by `mask` with `new`, if it is currently `current`.
+///
+/// Returns the previous value of the bit, in `Ok` if it was replaced.
+#[cfg(target_has_atomic = "8")]
+fn compare_exchange_bit<A : AtomicByte>(byte : &A, mask : u8, current : bool, new : bool, success : Ordering, failure : Ordering) -> Result<bool, bool> {
+    let mut old = byte.load(failure);
+    loop {
+        let bit = (old & mask) != 0;
+        if (bit != current) { return Err(bit); }
+        let new_byte = if (new) { old | mask } else { old & (! mask) };
+        match (byte.compare_exchange_weak(old, new_byte, success, failure)) {
+            Ok(_)       => { return Ok(bit); },
+            Err(actual) => { old = actual; }
+        }
+    }
+}
+
+
+#[cfg(target_has_atomic = "8")]
+impl<O : BitOrder> BitPtrMut<O> {
+
+    /// Returns the mask of the bit that is pointed to, within its byte.
+    #[inline(always)]
+    const fn mask(self) -> u8 {
+        reorder::<O>(0b10000000 >> self.subbyte_bit().get())
+    }
+
+    /// Returns the byte that is pointed to, as an atomic.
+    ///
+    /// # Safety
+    /// See [`AtomicU8::from_ptr`].
+    #[inline(always)]
+    const unsafe fn atomic_byte<'a>(self) -> &'a AtomicU8 {
+        unsafe { AtomicU8::from_ptr(self.floor_byte()) }
+    }
+
+    /// Atomically reads the bit that is pointed to.
+    ///
+    /// # Safety
+    /// Behaviour is undefined if `self.floor_byte()` is not [valid](core::ptr#safety) for reads,
+    ///  or if the byte is accessed non-atomically at the same time. See [`AtomicU8::from_ptr`].
+    ///
+    /// # Panics
+    /// Panics if `order` is [`Release`](Ordering::Release) or [`AcqRel`](Ordering::AcqRel).
+    #[inline]
+    pub unsafe fn atomic_load(self, order : Ordering) -> bool {
+        (unsafe { self.atomic_byte() }.load(order) & self.mask()) != 0
+    }
+
+    /// Atomically sets the bit that is pointed to to `1`.
+    ///
+    /// Other bits of the same byte are not affected, even if they are modified at the same time.
+    ///
+    /// # Safety
+    /// Behaviour is undefined if `self.floor_byte()` is not [valid](core::ptr#safety) for reads and writes,
+    ///  or if the byte is accessed non-atomically at the same time. See [`AtomicU8::from_ptr`].
+    #[inline]
+    pub unsafe fn atomic_set(self, order : Ordering) {
+        unsafe { self.atomic_byte() }.fetch_or(self.mask(), order);
+    }
+
+    /// Atomically sets the bit that is pointed to to `0`.
+    ///
+    /// Other bits of the same byte are not affected, even if they are modified at the same time.
+    ///
+    /// # Safety
+    /// Behaviour is undefined if `self.floor_byte()` is not [valid](core::ptr#safety) for reads and writes,
+    ///  or if the byte is accessed non-atomically at the same time. See [`AtomicU8::from_ptr`].
+    #[inline]
+    pub unsafe fn atomic_clear(self, order : Ordering) {
+        unsafe { self.atomic_byte() }.fetch_and(! self.mask(), order);
+    }
+
+    /// Atomically inverts the bit that is pointed to.
+    ///
+    /// Other bits of the same byte are not affected, even if they are modified at the same time.
+    ///
+    /// # Returns
+    /// Returns the previous value of the bit.
+    ///
+    /// # Safety
+    /// Behaviour is undefined if `self.floor_byte()` is not [valid](core::ptr#safety) for reads and writes,
+    ///  or if the byte is accessed non-atomically at the same time. See [`AtomicU8::from_ptr`].
+    #[inline]
+    pub unsafe fn atomic_toggle(self, order : Ordering) -> bool {
+        let mask = self.mask();
+        (unsafe { self.atomic_byte() }.fetch_xor(mask, order) & mask) != 0
+    }
+
+    /// Atomically sets the bit that is pointed to to `1`.
+    ///
+    /// # Returns
+    /// Returns the previous value of the bit.
+    ///
+    /// # Safety
+    /// Behaviour is undefined if `self.floor_byte()` is not [valid](core::ptr#safety) for reads and writes,
+    ///  or if the byte is accessed non-atomically at the same time. See [`AtomicU8::from_ptr`].
+    ///
+    /// # Examples
+    /// ```rust
+    /// use bitptr::BitPtrMut;
+    /// use core::sync::atomic::Ordering;
+    ///
+    /// let mut x = [0b_00000000_u8];
+    /// let xptr = unsafe { BitPtrMut::new_with_offset(x.as_mut_ptr(), 3) };
+    ///
+    /// assert_eq!(unsafe { xptr.atomic_test_and_set(Ordering::AcqRel) }, false);
+    /// assert_eq!(unsafe { xptr.atomic_test_and_set(Ordering::AcqRel) }, true);
+    /// assert_eq!(x, [0b_00010000_u8]);
+    /// ```
+    #[inline]
+    pub unsafe fn atomic_test_and_set(self, order : Ordering) -> bool {
+        let mask = self.mask();
+        (unsafe { self.atomic_byte() }.fetch_or(mask, order) & mask) != 0
+    }
+
+    /// Atomically sets the bit that is pointed to to `new`, if it is currently `current`.
+    ///
+    /// Other bits of the same byte are not affected, even if they are modified at the same time.
+    ///
+    /// # Returns
+    /// Returns `Ok` with the previous value of the bit if it was replaced, or `Err` with the current value of the bit if it was not.
+    ///
+    /// # Safety
+    /// Behaviour is undefined if `self.floor_byte()` is not [valid](core::ptr#safety) for reads and writes,
+    ///  or if the byte is accessed non-atomically at the same time. See [`AtomicU8::from_ptr`].
+    ///
+    /// # Panics
+    /// Panics if `failure` is [`Release`](Ordering::Release) or [`AcqRel`](Ordering::AcqRel).
+    ///
+    /// ---
+    /// Analagous to [`AtomicBool::compare_exchange`](core::sync::atomic::AtomicBool::compare_exchange).
+    #[inline]
+    pub unsafe fn atomic_compare_exchange(self, current : bool, new : bool, success : Ordering, failure : Ordering) -> Result<bool, bool> {
+        compare_exchange_bit(unsafe { self.atomic_byte() }, self.mask(), current, new, success, failure)
+    }
+
+}
+
+
+/// A bit pointer which can be safely shared between threads.
+///
+/// The bit pointer is stored as a single [`AtomicPtr`](core::sync::atomic::AtomicPtr), whose address is the [bit address](BitPtrMut::bit_addr) of the bit pointer.
+///  The provenance of the byte pointer is kept, and every operation is lock-free wherever `AtomicPtr` is.
+///
+/// Because the bit address is the byte address multiplied by `8`, only bit pointers whose byte address is at most [`MAX_ADDR`](AtomicBitPtr::MAX_ADDR) can be stored.
+///  On 64-bit targets this covers every address in use, but on 32-bit targets it excludes addresses from `0x2000_0000` upwards,
+///  which often includes memory-mapped peripherals. Storing a bit pointer above the limit panics, rather than losing bits of its address.
+///
+/// Analagous to [`AtomicPtr`](core::sync::atomic::AtomicPtr).
+#[cfg(target_has_atomic = "ptr")]
+pub struct AtomicBitPtr<O : BitOrder = Msb0> {
+    ptr   : AtomicPtr<u8>,
+    order : PhantomData<O>
+}
+
+/// Formats the bit pointer loaded with [`Relaxed`](Ordering::Relaxed) ordering.
+#[cfg(target_has_atomic = "ptr")]
+impl<O : BitOrder> fmt::Debug for AtomicBitPtr<O> {
+    fn fmt(&self, f : &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.load(Ordering::Relaxed), f)
+    }
+}
+
+#[cfg(target_has_atomic = "ptr")]
+impl<O : BitOrder> Default for AtomicBitPtr<O> {
+    /// Creates a null `AtomicBitPtr`.
+    fn default() -> Self {
+        Self::new(crate::null_mut().cast_order())
+    }
+}
+
+#[cfg(target_has_atomic = "ptr")]
+impl<O : BitOrder> AtomicBitPtr<O> {
+
+    /// The highest byte address of a bit pointer which can be stored.
+    pub const MAX_ADDR : usize = usize::MAX / 8;
+
+    /// Packs a bit pointer into a byte pointer whose address is its bit address.
+    ///
+    /// # Panics
+    /// Panics if the byte address of `ptr` is greater than [`MAX_ADDR`](Self::MAX_ADDR).
+    #[inline(always)]
+    fn pack(ptr : BitPtrMut<O>) -> *mut u8 {
+        assert!(ptr.addr() <= Self::MAX_ADDR, "bit pointer address is too high to be stored in an `AtomicBitPtr`");
+        ptr.floor_byte().with_addr((ptr.addr() * 8) + (ptr.subbyte_bit().get() as usize))
+    }
+
+    /// Unpacks a byte pointer created by [`pack`](Self::pack).
+    #[inline(always)]
+    fn unpack(ptr : *mut u8) -> BitPtrMut<O> {
+        BitPtrMut::new_on_byte(ptr).cast_order().with_bit_addr(ptr.addr())
+    }
+
+    /// Creates a new `AtomicBitPtr`.
+    ///
+    /// # Panics
+    /// Panics if the byte address of `ptr` is greater than [`MAX_ADDR`](Self::MAX_ADDR).
+    #[inline]
+    pub fn new(ptr : BitPtrMut<O>) -> Self {
+        Self { ptr : AtomicPtr::new(Self::pack(ptr)), order : PhantomData }
+    }
+
+    /// Loads the bit pointer.
+    ///
+    /// # Panics
+    /// Panics if `order` is [`Release`](Ordering::Release) or [`AcqRel`](Ordering::AcqRel).
+    #[inline]
+    pub fn load(&self, order : Ordering) -> BitPtrMut<O> {
+        Self::unpack(self.ptr.load(order))
+    }
+
+    /// Stores a bit pointer.
+    ///
+    /// # Panics
+    /// Panics if `order` is [`Acquire`](Ordering::Acquire) or [`AcqRel`](Ordering::AcqRel),
+    ///  or if the byte address of `ptr` is greater than [`MAX_ADDR`](Self::MAX_ADDR).
+    #[inline]
+    pub fn store(&self, ptr : BitPtrMut<O>, order : Ordering) {
+        self.ptr.store(Self::pack(ptr), order);
+    }
+
+    /// Stores a bit pointer, returning the previous bit pointer.
+    ///
+    /// # Panics
+    /// Panics if the byte address of `ptr` is greater than [`MAX_ADDR`](Self::MAX_ADDR).
+    #[inline]
+    pub fn swap(&self, ptr : BitPtrMut<O>, order : Ordering) -> BitPtrMut<O> {
+        Self::unpack(self.ptr.swap(Self::pack(ptr), order))
+    }
+
+    /// Stores `new` if the current bit pointer is `current`.
+    ///
+    /// # Returns
+    /// Returns `Ok` with the previous bit pointer if it was replaced, or `Err` with the current bit pointer if it was not.
+    ///
+    /// # Panics
+    /// Panics if `failure` is [`Release`](Ordering::Release) or [`AcqRel`](Ordering::AcqRel),
+    ///  or if the byte address of `current` or `new` is greater than [`MAX_ADDR`](Self::MAX_ADDR).
+    ///
+    /// ---
+    /// Analagous to [`AtomicPtr::compare_exchange`](core::sync::atomic::AtomicPtr::compare_exchange).
+    #[inline]
+    pub fn compare_exchange(&self, current : BitPtrMut<O>, new : BitPtrMut<O>, success : Ordering, failure : Ordering) -> Result<BitPtrMut<O>, BitPtrMut<O>> {
+        self.ptr.compare_exchange(Self::pack(current), Self::pack(new), success, failure)
+            .map(Self::unpack).map_err(Self::unpack)
+    }
+
+    /// Stores `new` if the current bit pointer is `current`. Unlike [`compare_exchange`](Self::compare_exchange), this may spuriously fail.
+    ///
+    /// # Returns
+    /// Returns `Ok` with the previous bit pointer if it was replaced, or `Err` with the current bit pointer if it was not.
+    ///
+    /// # Panics
+    /// Panics if `failure` is [`Release`](Ordering::Release) or [`AcqRel`](Ordering::AcqRel),
+    ///  or if the byte address of `current` or `new` is greater than [`MAX_ADDR`](Self::MAX_ADDR).
+    ///
+    /// ---
+    /// Analagous to [`AtomicPtr::compare_exchange_weak`](core::sync::atomic::AtomicPtr::compare_exchange_weak).
+    #[inline]
+    pub fn compare_exchange_weak(&self, current : BitPtrMut<O>, new : BitPtrMut<O>, success : Ordering, failure : Ordering) -> Result<BitPtrMut<O>, BitPtrMut<O>> {
+        self.ptr.compare_exchange_weak(Self::pack(current), Self::pack(new), success, failure)
+            .map(Self::unpack).map_err(Self::unpack)
+    }
+
+}
+
+
+#[cfg(all(test, not(loom)))]
+mod tests {
+    use super::*;
+    use crate::{ Lsb0, SubByte };
+    use std::thread;
+
+
+    #[test]
+    fn atomic_bit_ops() {
+        let mut x = [0b01011011u8];
+
+        let xptr = BitPtrMut::new_on_byte(x.as_mut_ptr());
+        unsafe {
+            xptr.atomic_set(Ordering::Relaxed);
+            xptr.bit_add(1).atomic_clear(Ordering::Relaxed);
+            assert_eq!(xptr.bit_add(2).atomic_toggle(Ordering::Relaxed), false);
+            assert_eq!(xptr.bit_add(3).atomic_test_and_set(Ordering::Relaxed), true);
+            assert_eq!(xptr.bit_add(5).atomic_compare_exchange(true, false, Ordering::Relaxed, Ordering::Relaxed), Err(false));
+            assert_eq!(xptr.bit_add(6).atomic_compare_exchange(true, false, Ordering::Relaxed, Ordering::Relaxed), Ok(true));
+            assert_eq!(xptr.cast_order::<Lsb0>().atomic_compare_exchange(true, true, Ordering::Relaxed, Ordering::Relaxed), Ok(true));
+            assert_eq!(xptr.bit_add(7).atomic_load(Ordering::Relaxed), true);
+        }
+        assert_eq!(x, [0b10111001u8]);
+    }
+
+
+    #[test]
+    fn atomic_bit_ops_threads() {
+        let mut x = [0u8; 4];
+
+        let shared = AtomicBitPtr::new(BitPtrMut::new_on_byte(x.as_mut_ptr()));
+        thread::scope(|s| {
+            for i in 0..8 {
+                let shared = &shared;
+                s.spawn(move || {
+                    let ptr = shared.load(Ordering::Acquire);
+                    for j in 0..4 {
+                        unsafe { ptr.bit_add((j * 8) + i).atomic_set(Ordering::Relaxed); }
+                    }
+                });
+            }
+        });
+        assert_eq!(x, [u8::MAX; 4]);
+    }
+
+
+    #[test]
+    fn atomic_bitptr() {
+        let mut x = [0u8; 2];
+
+        let aptr = unsafe { BitPtrMut::new_with_offset(x.as_mut_ptr(), 3) };
+        let bptr = unsafe { BitPtrMut::new_with_offset(x.as_mut_ptr(), 13) };
+        let shared = AtomicBitPtr::new(aptr);
+        assert!(shared.load(Ordering::Relaxed) == aptr);
+        assert!(shared.compare_exchange(bptr, bptr, Ordering::Relaxed, Ordering::Relaxed) == Err(aptr));
+        assert!(shared.compare_exchange(aptr, bptr, Ordering::Relaxed, Ordering::Relaxed) == Ok(aptr));
+        assert!(shared.swap(aptr, Ordering::Relaxed) == bptr);
+        unsafe { shared.load(Ordering::Relaxed).write(true); }
+        assert_eq!(x, [0b00010000u8, 0]);
+        assert!(AtomicBitPtr::<Lsb0>::default().load(Ordering::Relaxed) == crate::null_mut().cast_order());
+    }
+
+
+    #[test]
+    fn atomic_bitptr_max_addr() {
+        let aptr = BitPtrMut::new(core::ptr::without_provenance_mut(AtomicBitPtr::<Msb0>::MAX_ADDR), SubByte::MAX);
+        let bptr = BitPtrMut::new(core::ptr::without_provenance_mut(AtomicBitPtr::<Msb0>::MAX_ADDR - 1), SubByte::MIN);
+        let shared = AtomicBitPtr::new(aptr);
+        assert!(shared.load(Ordering::Relaxed) == aptr);
+        assert!(shared.compare_exchange(aptr, bptr, Ordering::AcqRel, Ordering::Acquire) == Ok(aptr));
+        assert!(shared.swap(aptr, Ordering::SeqCst) == bptr);
+        shared.store(bptr, Ordering::Release);
+        assert!(shared.compare_exchange(aptr, aptr, Ordering::Relaxed, Ordering::Relaxed) == Err(bptr));
+    }
+
+
+    #[test]
+    #[should_panic(expected = "too high")]
+    fn atomic_bitptr_above_max_addr() {
+        let shared = AtomicBitPtr::default();
+        shared.store(BitPtrMut::new_on_byte(core::ptr::without_provenance_mut(AtomicBitPtr::<Msb0>::MAX_ADDR + 1)), Ordering::Relaxed);
+    }
+
+}
+
+
+#[cfg(all(test, loom))]
+mod loom_tests {
+    use super::*;
+    use loom::sync::Arc;
+    use loom::sync::atomic::AtomicU8;
+    use loom::thread;
+
+
+    #[test]
+    fn loom_compare_exchange_bit() {
+        loom::model(|| {
+            let byte = Arc::new(AtomicU8::new(0));
+            let spawn = |mask : u8| {
+                let byte = byte.clone();
+                thread::spawn(move || {
+                    // Two threads race to claim bit 0, while a third sets bit 1.
+                    compare_exchange_bit(&*byte, mask, false, true, Ordering::AcqRel, Ordering::Acquire).is_ok()
+                })
+            };
+            let a = spawn(0b00000001);
+            let b = spawn(0b00000001);
+            let c = spawn(0b00000010);
+            let claimed = [a.join().unwrap(), b.join().unwrap()];
+            assert!(c.join().unwrap());
+            assert_eq!(claimed.iter().filter(|&&c| c).count(), 1);
+            assert_eq!(byte.load(Ordering::Relaxed), 0b00000011);
+        });
+    }
+
+
+    #[test]
+    fn loom_atomic_bitptr() {
+        loom::model(|| {
+            let mut x = [0u8; 2];
+            let xptr   = BitPtrMut::new_on_byte(x.as_mut_ptr());
+            let shared = Arc::new(AtomicBitPtr::new(xptr));
+            let spawn  = || {
+                let shared = shared.clone();
+                thread::spawn(move || {
+                    // Advance the shared bit pointer by one bit.
+                    let mut current = shared.load(Ordering::Acquire);
+                    while let Err(actual) = shared.compare_exchange_weak(current, current.wrapping_bit_add(1), Ordering::AcqRel, Ordering::Acquire) {
+                        current = actual;
+                    }
+                })
+            };
+            let a = spawn();
+            let b = spawn();
+            a.join().unwrap();
+            b.join().unwrap();
+            assert!(shared.load(Ordering::Acquire) == xptr.wrapping_bit_add(2));
+        });
+    }
+
+}
@@ -0,0 +1,304 @@
+use crate::{ BitPtr, BitPtrMut, BitOrder };
+use crate::order::{ reorder, reorder_u64 };
+use crate::copy::read_in_bounds;
+use crate::simd;
+
+
+/// Performs `dst &= src` over `bit_count` bits.
+///
+/// `src` and `dst` may have different sub-byte alignments, and may use different [bit orders](BitOrder). Bit `n` of the source region is combined into bit `n` of the destination region.
+///
+/// # Safety
+/// Behaviour is undefined if any of the following conditions are violated:
+/// - `src.floor_byte()` must be [valid](core::ptr#safety) for reads of `((src.subbyte_bit().get() as usize) + bit_count).div_ceil(8)` bytes.
+/// - `dst.floor_byte()` must be [valid](core::ptr#safety) for reads and writes of `((dst.subbyte_bit().get() as usize) + bit_count).div_ceil(8)` bytes.
+/// - The region of memory beginning at `src` with a size of `bit_count` bits must *not* overlap with the region of memory beginning at `dst` with the same size.
+///   The byte region may overlap. The relevant bits themselves may not.
+///
+/// # Examples
+/// ```rust
+/// use bitptr::{ BitPtr, BitPtrMut };
+///
+/// let     x = [0b_11110000_u8];
+/// let mut y = [0b_10101010_u8, 0b_11111111_u8];
+///
+/// let xptr = BitPtr::new_on_byte(x.as_ptr());
+/// let yptr = unsafe { BitPtrMut::new_with_offset(y.as_mut_ptr(), 4) };
+///
+/// unsafe { bitptr::and_assign(xptr, yptr, 8); }
+/// assert_eq!(y, [0b_10101010_u8, 0b_00001111_u8]);
+/// ```
+pub unsafe fn and_assign<S : BitOrder, D : BitOrder>(src : BitPtr<S>, dst : BitPtrMut<D>, bit_count : usize) {
+    unsafe { combine(src, dst, bit_count, Op::And); }
+}
+
+
+/// Performs `dst |= src` over `bit_count` bits.
+///
+/// # Safety
+/// See [`and_assign`].
+///
+/// # Examples
+/// ```rust
+/// use bitptr::{ BitPtr, BitPtrMut };
+///
+/// let     x = [0b_11110000_u8];
+/// let mut y = [0b_10101010_u8, 0b_00000000_u8];
+///
+/// let xptr = BitPtr::new_on_byte(x.as_ptr());
+/// let yptr = unsafe { BitPtrMut::new_with_offset(y.as_mut_ptr(), 4) };
+///
+/// unsafe { bitptr::or_assign(xptr, yptr, 8); }
+/// assert_eq!(y, [0b_10101111_u8, 0b_00000000_u8]);
+/// ```
+pub unsafe fn or_assign<S : BitOrder, D : BitOrder>(src : BitPtr<S>, dst : BitPtrMut<D>, bit_count : usize) {
+    unsafe { combine(src, dst, bit_count, Op::Or); }
+}
+
+
+/// Performs `dst ^= src` over `bit_count` bits.
+///
+/// # Safety
+/// See [`and_assign`].
+pub unsafe fn xor_assign<S : BitOrder, D : BitOrder>(src : BitPtr<S>, dst : BitPtrMut<D>, bit_count : usize) {
+    unsafe { combine(src, dst, bit_count, Op::Xor); }
+}
+
+
+/// Performs `dst &= !src` over `bit_count` bits, clearing every bit of `dst` whose matching bit of `src` is set.
+///
+/// # Safety
+/// See [`and_assign`].
+pub unsafe fn andnot_assign<S : BitOrder, D : BitOrder>(src : BitPtr<S>, dst : BitPtrMut<D>, bit_count : usize) {
+    unsafe { combine(src, dst, bit_count, Op::AndNot); }
+}
+
+
+/// Inverts `bit_count` bits at `dst`.
+///
+/// # Safety
+/// Behaviour is undefined if `dst.floor_byte()` is not [valid](core::ptr#safety) for reads and writes of `((dst.subbyte_bit().get() as usize) + bit_count).div_ceil(8)` bytes.
+///
+/// # Examples
+/// ```rust
+/// use bitptr::BitPtrMut;
+///
+/// let mut x = [0b_01011011_u8, 0b_10010110_u8];
+///
+/// let xptr = unsafe { BitPtrMut::new_with_offset(x.as_mut_ptr(), 6) };
+///
+/// unsafe { bitptr::invert(xptr, 4); }
+/// assert_eq!(x, [0b_01011000_u8, 0b_01010110_u8]);
+/// ```
+pub unsafe fn invert<O : BitOrder>(dst : BitPtrMut<O>, bit_count : usize) {
+    if (bit_count == 0) { return; }
+
+    let (dst_byte, dst_bit,) = dst.as_inner();
+    let dst_bit_l = dst_bit.get() as usize;
+    let dst_bit_r = (8isize - ((dst_bit_l + bit_count) as isize)).rem_euclid(8);
+
+    let dst_byte_count = (dst_bit_l + bit_count).div_ceil(8);
+    let (full_start, full_end,) = full_bytes(dst_byte_count, dst_bit_l, dst_bit_r);
+
+    let mut dst_offset = 0;
+    while (dst_offset < dst_byte_count) {
+        let dst_byte = unsafe { dst_byte.byte_add(dst_offset) };
+
+        // Whole words in the middle of the range are inverted all at once. Bit order does not matter here.
+        if (dst_offset >= full_start && dst_offset + 8 <= full_end) {
+            let dst_word = dst_byte.cast::<u64>();
+            unsafe { dst_word.write_unaligned(! dst_word.read_unaligned()); }
+            dst_offset += 8;
+            continue;
+        }
+
+        unsafe { *dst_byte ^= reorder::<O>(byte_mask(dst_offset, dst_byte_count, dst_bit_l, dst_bit_r)); }
+        dst_offset += 1;
+    }
+}
+
+
+/// A bitwise operation which combines a source value into a destination value.
+#[derive(Clone, Copy)]
+pub(crate) enum Op {
+    And,
+    Or,
+    Xor,
+    AndNot
+}
+
+impl Op {
+
+    #[inline(always)]
+    pub(crate) fn apply(self, dst : u64, src : u64) -> u64 {
+        match (self) {
+            Op::And    => dst & src,
+            Op::Or     => dst | src,
+            Op::Xor    => dst ^ src,
+            Op::AndNot => dst & (! src)
+        }
+    }
+
+}
+
+
+/// Combines `bit_count` bits from `src` into `dst` using `op`, one destination byte at a time.
+///
+/// If `src` and `dst` have the same sub-byte alignment, whole words in the middle of the range are combined all at once.
+///
+/// # Safety
+/// See [`and_assign`].
+unsafe fn combine<S : BitOrder, D : BitOrder>(src : BitPtr<S>, dst : BitPtrMut<D>, bit_count : usize, op : Op) {
+    if (bit_count == 0) { return; }
+
+    let (src_byte, src_bit,) = src.as_inner();
+    let src_bit_l = src_bit.get() as usize;
+    let (dst_byte, dst_bit,) = dst.as_inner();
+    let dst_bit_l = dst_bit.get() as usize;
+    let dst_bit_r = (8isize - ((dst_bit_l + bit_count) as isize)).rem_euclid(8);
+
+    let src_byte_count = (src_bit_l + bit_count).div_ceil(8);
+    let dst_byte_count = (dst_bit_l + bit_count).div_ceil(8);
+
+    // Find the source byte that lines up with the first destination byte, and the bit shift between them.
+    let src_shift = (src_bit_l as isize) - (dst_bit_l as isize);
+    let src_first = src_shift.div_euclid(8);
+    let src_shift = src_shift.rem_euclid(8) as u32;
+
+    // When the alignments match, each source byte lines up with exactly one destination byte.
+    let aligned = src_bit_l == dst_bit_l;
+    let (full_start, full_end,) = full_bytes(dst_byte_count, dst_bit_l, dst_bit_r);
+
+    // The rolling source value holds the two source bytes that the current destination byte is built from.
+    let mut src_rolling = reorder::<S>(unsafe { read_in_bounds(src_byte, src_byte_count, src_first) }) as u16;
+
+    let mut offset = 0;
+    while (offset < dst_byte_count) {
+        let dst_byte = unsafe { dst_byte.byte_add(offset) };
+
+        // When the bit orders also match, the whole bytes can be combined as they are in memory.
+        if (aligned && offset == full_start && S::LSB_FIRST == D::LSB_FIRST) {
+            let handled = unsafe { simd::combine(src_byte.byte_add(offset), dst_byte, full_end - offset, op) };
+            if (handled > 0) {
+                offset += handled;
+                continue;
+            }
+        }
+
+        // Combine whole words in the middle of the range all at once.
+        if (aligned && offset >= full_start && offset + 8 <= full_end) {
+            let src_word = unsafe { src_byte.byte_add(offset) }.cast::<u64>();
+            let dst_word = dst_byte.cast::<u64>();
+            let src_w    = reorder_u64::<S>(u64::from_be(unsafe { src_word.read_unaligned() }));
+            let dst_w    = reorder_u64::<D>(u64::from_be(unsafe { dst_word.read_unaligned() }));
+            unsafe { dst_word.write_unaligned(reorder_u64::<D>(op.apply(dst_w, src_w)).to_be()); }
+            offset += 8;
+            continue;
+        }
+
+        // Build the byte that will be combined.
+        let src_b = if (aligned) {
+            reorder::<S>(unsafe { *src_byte.byte_add(offset) })
+        } else {
+            src_rolling = (src_rolling << 8) | (reorder::<S>(unsafe { read_in_bounds(src_byte, src_byte_count, src_first + (offset as isize) + 1) }) as u16);
+            (src_rolling << src_shift >> 8) as u8
+        };
+
+        // Combine the relevant bits, and leave the rest untouched.
+        let dst_mask = byte_mask(offset, dst_byte_count, dst_bit_l, dst_bit_r);
+        let dst_b    = reorder::<D>(unsafe { *dst_byte });
+        let new_b    = op.apply(dst_b as u64, src_b as u64) as u8;
+        unsafe { *dst_byte = reorder::<D>((dst_b & (! dst_mask)) | (new_b & dst_mask)); }
+
+        offset += 1;
+    }
+
+}
+
+
+/// Returns the range of byte offsets which are fully covered by the bit range.
+#[inline(always)]
+fn full_bytes(byte_count : usize, bit_l : usize, bit_r : isize) -> (usize, usize,) {
+    let start = if (bit_l == 0) { 0 } else { 1 };
+    let end   = if (bit_r == 0) { byte_count } else { byte_count - 1 };
+    (start, end.max(start),)
+}
+
+
+/// Returns a mask over the bits of the byte at `offset` which are part of the bit range, in [`Msb0`](crate::Msb0) order.
+#[inline(always)]
+fn byte_mask(offset : usize, byte_count : usize, bit_l : usize, bit_r : isize) -> u8 {
+    let mut mask = u8::MAX;
+    if (offset == 0) {
+        mask = mask << bit_l >> bit_l;
+    }
+    if (offset + 1 == byte_count) {
+        mask = mask >> bit_r << bit_r;
+    }
+    mask
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ Msb0, Lsb0 };
+
+
+    const X : [u8; 20] = [
+        0b01011011, 0b10010110, 0b11100001, 0b00111100, 0b01011011, 0b10010110, 0b11100001, 0b00111100, 0b10101010, 0b01010101,
+        0b11001100, 0b00110011, 0b11110000, 0b00001111, 0b01011011, 0b10010110, 0b11100001, 0b00111100, 0b10101010, 0b01010101
+    ];
+    const Y : [u8; 20] = [
+        0b11100001, 0b00111100, 0b10101010, 0b01010101, 0b11001100, 0b00110011, 0b11110000, 0b00001111, 0b01011011, 0b10010110,
+        0b01011011, 0b10010110, 0b11100001, 0b00111100, 0b01011011, 0b10010110, 0b11100001, 0b00111100, 0b10101010, 0b01010101
+    ];
+
+
+    fn check<S : BitOrder, D : BitOrder>(f : unsafe fn(BitPtr<S>, BitPtrMut<D>, usize), model : fn(bool, bool) -> bool) {
+        for src_bit in 0..8 {
+            for dst_bit in 0..8 {
+                for bit_count in [0, 1, 7, 8, 9, 63, 64, 65, 100, 150] {
+                    let mut y    = Y;
+                    let     xptr = unsafe { BitPtr::new_on_byte(X.as_ptr()).cast_order::<S>().bit_add(src_bit) };
+                    let     yptr = unsafe { BitPtrMut::new_on_byte(y.as_mut_ptr()).cast_order::<D>().bit_add(dst_bit) };
+                    let     old  = BitPtr::new_on_byte(Y.as_ptr()).cast_order::<D>();
+                    unsafe { f(xptr, yptr, bit_count); }
+                    for i in 0..(Y.len() * 8) {
+                        let expected = if (i >= dst_bit && i < dst_bit + bit_count) {
+                            model(unsafe { old.bit_add(i).read() }, unsafe { xptr.bit_add(i - dst_bit).read() })
+                        } else { unsafe { old.bit_add(i).read() } };
+                        assert_eq!(unsafe { yptr.as_const().bit_sub(dst_bit).bit_add(i).read() }, expected);
+                    }
+                }
+            }
+        }
+    }
+
+
+    #[test]
+    fn combine_unaligned() {
+        check::<Msb0, Msb0>(and_assign, |d, s| d & s);
+        check::<Msb0, Msb0>(or_assign, |d, s| d | s);
+        check::<Msb0, Lsb0>(xor_assign, |d, s| d ^ s);
+        check::<Lsb0, Msb0>(andnot_assign, |d, s| d & (! s));
+    }
+
+
+    #[test]
+    fn invert_unaligned() {
+        for dst_bit in 0..8 {
+            for bit_count in [0, 1, 7, 8, 9, 63, 64, 65, 100, 150] {
+                let mut y    = Y;
+                let     yptr = unsafe { BitPtrMut::new_on_byte(y.as_mut_ptr()).cast_order::<Lsb0>().bit_add(dst_bit) };
+                let     old  = BitPtr::new_on_byte(Y.as_ptr()).cast_order::<Lsb0>();
+                unsafe { invert(yptr, bit_count); }
+                for i in 0..(Y.len() * 8) {
+                    let flipped = i >= dst_bit && i < dst_bit + bit_count;
+                    assert_eq!(unsafe { yptr.as_const().bit_sub(dst_bit).bit_add(i).read() }, unsafe { old.bit_add(i).read() } ^ flipped);
+                }
+            }
+        }
+    }
+
+}
@@ -0,0 +1,112 @@
+use crate::{ BitPtr, BitOrder };
+use crate::words::Words;
+use core::cmp::Ordering;
+
+
+/// Compares `bit_count` bits at `a` with `bit_count` bits at `b` for equality.
+///
+/// `a` and `b` may have different sub-byte alignments, and may use different [bit orders](BitOrder). Bit `n` of one region is compared with bit `n` of the other.
+///
+/// # Safety
+/// Behaviour is undefined if any of the following conditions are violated:
+/// - `a.floor_byte()` must be [valid](core::ptr#safety) for reads of `((a.subbyte_bit().get() as usize) + bit_count).div_ceil(8)` bytes.
+/// - `b.floor_byte()` must be [valid](core::ptr#safety) for reads of `((b.subbyte_bit().get() as usize) + bit_count).div_ceil(8)` bytes.
+///
+/// # Examples
+/// ```rust
+/// use bitptr::BitPtr;
+///
+/// let x = [0b_01011011_u8, 0b_10010110_u8];
+/// //             ^^^^^^^^^^ This region...
+/// let y = [0b_11011100_u8, 0b_10010000_u8];
+/// //          ^^^^^^^^^^ ...is equal to this region.
+///
+/// let xptr = unsafe { BitPtr::new_with_offset(x.as_ptr(), 3) };
+/// let yptr = unsafe { BitPtr::new_with_offset(y.as_ptr(), 0) };
+/// assert!(unsafe { bitptr::bits_eq(xptr, yptr, 10) });
+/// assert!(! unsafe { bitptr::bits_eq(xptr, yptr, 11) });
+/// ```
+pub unsafe fn bits_eq<A : BitOrder, B : BitOrder>(a : BitPtr<A>, b : BitPtr<B>, bit_count : usize) -> bool {
+    let a = unsafe { Words::new(a, bit_count) };
+    let b = unsafe { Words::new(b, bit_count) };
+    a.eq(b)
+}
+
+
+/// Compares `bit_count` bits at `a` with `bit_count` bits at `b` lexicographically.
+///
+/// The bits are compared in order, starting from the bit that is pointed to. The first bit that differs decides the result, with `0` being less than `1`.
+///
+/// `a` and `b` may have different sub-byte alignments, and may use different [bit orders](BitOrder). Bit `n` of one region is compared with bit `n` of the other.
+///
+/// # Safety
+/// Behaviour is undefined if any of the following conditions are violated:
+/// - `a.floor_byte()` must be [valid](core::ptr#safety) for reads of `((a.subbyte_bit().get() as usize) + bit_count).div_ceil(8)` bytes.
+/// - `b.floor_byte()` must be [valid](core::ptr#safety) for reads of `((b.subbyte_bit().get() as usize) + bit_count).div_ceil(8)` bytes.
+///
+/// # Examples
+/// ```rust
+/// use bitptr::BitPtr;
+/// use core::cmp::Ordering;
+///
+/// let x = [0b_01011011_u8];
+/// let y = [0b_01100000_u8];
+///
+/// let xptr = BitPtr::new_on_byte(x.as_ptr());
+/// let yptr = BitPtr::new_on_byte(y.as_ptr());
+/// assert_eq!(unsafe { bitptr::bits_cmp(xptr, yptr, 2) }, Ordering::Equal);
+/// assert_eq!(unsafe { bitptr::bits_cmp(xptr, yptr, 8) }, Ordering::Less);
+/// ```
+pub unsafe fn bits_cmp<A : BitOrder, B : BitOrder>(a : BitPtr<A>, b : BitPtr<B>, bit_count : usize) -> Ordering {
+    // Each word holds its bits most significant first, so comparing words as integers compares their bits lexicographically.
+    let a = unsafe { Words::new(a, bit_count) };
+    let b = unsafe { Words::new(b, bit_count) };
+    a.cmp(b)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Lsb0;
+
+
+    const X : [u8; 10] = [0b01011011, 0b10010110, 0b11100001, 0b00111100, 0b01011011, 0b10010110, 0b11100001, 0b00111100, 0b10101010, 0b01010101];
+
+
+    fn model<O : BitOrder>(ptr : BitPtr<O>, bit_count : usize) -> Vec<bool> {
+        (0..bit_count).map(|i| unsafe { ptr.bit_add(i).read() }).collect()
+    }
+
+
+    #[test]
+    fn bits_eq_cmp_unaligned() {
+        let xptr = BitPtr::new_on_byte(X.as_ptr());
+        for a in 0..16 {
+            for b in 0..16 {
+                for bit_count in [0, 1, 7, 8, 9, 31, 63, 64, 65] {
+                    let aptr = unsafe { xptr.bit_add(a) };
+                    let bptr = unsafe { xptr.bit_add(b) };
+                    let am = model(aptr, bit_count);
+                    let bm = model(bptr, bit_count);
+                    assert_eq!(unsafe { bits_eq(aptr, bptr, bit_count) }, am == bm);
+                    assert_eq!(unsafe { bits_cmp(aptr, bptr, bit_count) }, am.cmp(&bm));
+                }
+            }
+        }
+    }
+
+
+    #[test]
+    fn bits_eq_cmp_between_bit_orders() {
+        let x = [0b01011011u8, 0b10010110];
+        let y = [0b11011010u8, 0b01101001];
+
+        let xptr = BitPtr::new_on_byte(x.as_ptr());
+        let yptr = BitPtr::new_on_byte(y.as_ptr()).cast_order::<Lsb0>();
+        assert!(unsafe { bits_eq(xptr, yptr, 16) });
+        assert_eq!(unsafe { bits_cmp(xptr, yptr, 16) }, Ordering::Equal);
+        assert_eq!(unsafe { bits_cmp(xptr.bit_add(1), yptr, 15) }, Ordering::Greater);
+    }
+
+}
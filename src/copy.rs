@@ -1,13 +1,17 @@
-use crate::{ BitPtr, BitPtrMut };
-use core::ptr;
+use crate::{ BitPtr, BitPtrMut, BitOrder };
+use crate::order::{ reorder, reorder_u64 };
+use crate::words::Words;
+use crate::simd;
 
 
 /// Copies `bit_count` bits from `src` to `dst`. The source and destination must *not* overlap.
 ///
-/// `bitptr` does not provide a `copy` function for memory which might overlap.
+/// For regions of memory which might overlap, use [`copy`] instead.
 ///
 /// The copy is "untyped" in the sense that data may be uninitialized. The initialization state is preserved exactly.
 ///
+/// `src` and `dst` may use different [bit orders](BitOrder). Bit `n` of the source region is copied to bit `n` of the destination region.
+///
 ///
 /// # Safety
 ///
@@ -55,10 +59,116 @@ use core::ptr;
 /// assert_eq!(u16::from_be(y), 0b_1111100101111111_u16);
 /// ```
 ///
+/// When the bits being copied are an integer field, [`BitPtr::read_bits`] and [`BitPtrMut::write_bits`] can be used instead.
+/// Their bit significance order does not depend on endianness.
+///
+/// [`display_bits`](crate::display_bits) can be used to see which bits of which bytes a bit pointer actually points to.
+///
 ///
 /// ---
 /// Analagous to [`ptr::copy_nonoverlapping`](core::ptr::copy_nonoverlapping).
-pub unsafe fn copy_nonoverlapping(src : BitPtr, dst : BitPtrMut, bit_count : usize) {
+pub unsafe fn copy_nonoverlapping<S : BitOrder, D : BitOrder>(src : BitPtr<S>, dst : BitPtrMut<D>, bit_count : usize) {
+    unsafe { copy_words(src, dst, bit_count); }
+}
+
+
+/// Copies `bit_count` bits from `src` to `dst`. The source and destination may overlap.
+///
+/// If the source and destination will *never* overlap, [`copy_nonoverlapping`] can be used instead.
+///
+/// `copy` is semantically equivalent to [`copy_nonoverlapping`], but with the bits copied as if they were first copied
+/// from `src` to a temporary region of memory, and then copied from the temporary region to `dst`.
+///
+/// The copy is "untyped" in the sense that data may be uninitialized. The initialization state is preserved exactly.
+///
+/// `src` and `dst` may use different [bit orders](BitOrder). Bit `n` of the source region is copied to bit `n` of the destination region.
+///
+///
+/// # Safety
+///
+/// Behavior is undefined if any of the following conditions are violated:
+/// - `src.floor_byte()` must be [valid](core::ptr#safety) for reads of `((src.subbyte_bit().get() as usize) + bit_count).div_ceil(8)` bytes.
+/// - `dst.floor_byte()` must be [valid](core::ptr#safety) for writes of `((dst.subbyte_bit().get() as usize) + bit_count).div_ceil(8)` bytes.
+///
+///
+/// # Footguns
+///
+/// Make sure to account for endianness. See [`copy_nonoverlapping`].
+///
+///
+/// ---
+/// Analagous to [`ptr::copy`](core::ptr::copy).
+pub unsafe fn copy<S : BitOrder, D : BitOrder>(src : BitPtr<S>, dst : BitPtrMut<D>, bit_count : usize) {
+    // Copying towards the start of memory is done front-to-back, so that no source bit is overwritten before it is read.
+    //  Copying towards the end of memory is done back-to-front for the same reason.
+    let (src_byte, src_bit,) = src.as_inner();
+    let (dst_byte, dst_bit,) = dst.as_inner();
+    let backward = (dst_byte.cast_const(), dst_bit,) > (src_byte, src_bit,);
+    unsafe { copy_directed(src, dst, bit_count, backward); }
+}
+
+
+/// Copies `bit_count` bits from `src` to `dst`, moving the destination bytes which are fully covered by the bit range a word at a time.
+///
+/// The partially covered destination bytes at the head and tail of the range are copied one byte at a time.
+///
+/// # Safety
+/// See [`copy_nonoverlapping`].
+unsafe fn copy_words<S : BitOrder, D : BitOrder>(src : BitPtr<S>, dst : BitPtrMut<D>, bit_count : usize) {
+    let (src_byte, src_bit,) = src.as_inner();
+    let (dst_byte, dst_bit,) = dst.as_inner();
+    let dst_bit_l = dst_bit.get() as usize;
+    let dst_bit_r = (8 - ((dst_bit_l + bit_count) % 8)) % 8;
+
+    // Find the destination bytes which are fully covered by the bit range.
+    let dst_byte_count = (dst_bit_l + bit_count).div_ceil(8);
+    let full_start     = if (dst_bit_l == 0) { 0 } else { 1 };
+    let full_end       = if (dst_bit_r == 0) { dst_byte_count } else { dst_byte_count - 1 };
+    if (full_end <= full_start) {
+        unsafe { copy_directed(src, dst, bit_count, false); }
+        return;
+    }
+    let full_count = full_end - full_start;
+
+    // Copy the partially covered head byte.
+    let head_bits = (full_start * 8) - dst_bit_l;
+    unsafe { copy_directed(src, dst, head_bits, false); }
+
+    // Copy the fully covered bytes.
+    let dst_full = unsafe { dst_byte.add(full_start) };
+    if (src_bit == dst_bit && S::LSB_FIRST == D::LSB_FIRST) {
+        // The bytes line up exactly, and the bit regions do not overlap, so neither do these bytes.
+        let src_full = unsafe { src_byte.add(full_start) };
+        let handled  = unsafe { simd::copy_nonoverlapping(src_full, dst_full, full_count) };
+        unsafe { core::ptr::copy_nonoverlapping(src_full.add(handled), dst_full.add(handled), full_count - handled); }
+    } else {
+        let words = unsafe { Words::new(src.bit_add(head_bits), full_count * 8) };
+        for (i, word,) in words.enumerate() {
+            let offset = i * 8;
+            if (offset + 8 <= full_count) {
+                unsafe { dst_full.add(offset).cast::<u64>().write_unaligned(reorder_u64::<D>(word).to_be()); }
+            } else {
+                for j in 0..(full_count - offset) {
+                    unsafe { *dst_full.add(offset + j) = reorder::<D>((word >> (56 - (j * 8))) as u8); }
+                }
+            }
+        }
+    }
+
+    // Copy the partially covered tail byte.
+    let copied = head_bits + (full_count * 8);
+    unsafe { copy_directed(src.bit_add(copied), dst.bit_add(copied), bit_count - copied, false); }
+}
+
+
+/// Copies `bit_count` bits from `src` to `dst`, one destination byte at a time.
+///
+/// If `backward` is `true`, the destination bytes are written from last to first. Otherwise they are written from first to last.
+///  Every source byte is read before the destination byte at the same index is written.
+///
+/// # Safety
+/// See [`copy`].
+unsafe fn copy_directed<S : BitOrder, D : BitOrder>(src : BitPtr<S>, dst : BitPtrMut<D>, bit_count : usize, backward : bool) {
     if (bit_count == 0) { return; }
 
     let (src_byte, src_bit,) = src.as_inner();
@@ -67,13 +177,32 @@ pub unsafe fn copy_nonoverlapping(src : BitPtr, dst : BitPtrMut, bit_count : usi
     let dst_bit_l = dst_bit.get() as usize;
     let dst_bit_r = (8isize - ((dst_bit_l + bit_count) as isize)).rem_euclid(8);
 
-    let mut src_rolling = u32::from_be(unsafe { ptr::read(src_byte.byte_sub(2) as *const u32) });
-
+    let src_byte_count = (src_bit_l + bit_count).div_ceil(8);
     let dst_byte_count = (dst_bit_l + bit_count).div_ceil(8);
-    for offset in 0..dst_byte_count {
-        let src_byte = unsafe { src_byte.byte_add(offset) };
+
+    // Find the source byte that lines up with the first destination byte, and the bit shift between them.
+    let src_shift = (src_bit_l as isize) - (dst_bit_l as isize);
+    let src_first = src_shift.div_euclid(8);
+    let src_shift = src_shift.rem_euclid(8) as u32;
+
+    // The rolling source value holds the two source bytes that the current destination byte is built from.
+    let mut src_rolling = if (backward) {
+        (reorder::<S>(unsafe { read_in_bounds(src_byte, src_byte_count, src_first + (dst_byte_count as isize)) }) as u16) << 8
+    } else {
+        reorder::<S>(unsafe { read_in_bounds(src_byte, src_byte_count, src_first) }) as u16
+    };
+
+    for i in 0..dst_byte_count {
+        let offset = if (backward) { dst_byte_count - i - 1 } else { i };
         let dst_byte = unsafe { dst_byte.byte_add(offset) };
 
+        // Roll the source value.
+        src_rolling = if (backward) {
+            (src_rolling >> 8) | ((reorder::<S>(unsafe { read_in_bounds(src_byte, src_byte_count, src_first + (offset as isize)) }) as u16) << 8)
+        } else {
+            (src_rolling << 8) | (reorder::<S>(unsafe { read_in_bounds(src_byte, src_byte_count, src_first + (offset as isize) + 1) }) as u16)
+        };
+
         // Get a mask over the bits to write.
         let mut dst_mask = u8::MAX;
         if (offset == 0) {
@@ -84,10 +213,10 @@ pub unsafe fn copy_nonoverlapping(src : BitPtr, dst : BitPtrMut, bit_count : usi
         }
 
         // Build the byte that will be written.
-        let src_b = ((src_rolling << src_bit_l >> (8 + dst_bit_l)) & 0b11111111) as u8;
+        let src_b = (src_rolling << src_shift >> 8) as u8;
 
         // Get the byte to edit.
-        let mut dst_b = unsafe { *dst_byte };
+        let mut dst_b = reorder::<D>(unsafe { *dst_byte });
 
         // Wipe the bits that will be overwritten.
         dst_b &= ! dst_mask;
@@ -96,19 +225,32 @@ pub unsafe fn copy_nonoverlapping(src : BitPtr, dst : BitPtrMut, bit_count : usi
         dst_b |= src_b & dst_mask;
 
         // Overwrite the byte.
-        unsafe { *dst_byte = dst_b; }
-
-        // Roll the source value.
-        src_rolling = (src_rolling << 8) | ((unsafe { src_byte.byte_add(1).read() }) as u32);
+        unsafe { *dst_byte = reorder::<D>(dst_b); }
 
     }
 
 }
 
 
+/// Reads the byte at `offset` bytes from `byte`, or `0` if it lies outside of the `byte_count` bytes starting at `byte`.
+///
+/// The bits of out-of-bounds bytes are never part of the bit region being operated on, so their value does not matter.
+///
+/// # Safety
+/// Behaviour is undefined if `byte` is not [valid](core::ptr#safety) for reads of `byte_count` bytes.
+#[inline(always)]
+pub(crate) unsafe fn read_in_bounds(byte : *const u8, byte_count : usize, offset : isize) -> u8 {
+    if (offset >= 0 && (offset as usize) < byte_count) {
+        unsafe { *byte.offset(offset) }
+    } else { 0 }
+}
+
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::Lsb0;
+    use crate::SubByte;
 
 
     #[test]
@@ -203,4 +345,129 @@ mod tests {
     }
 
 
+
+    #[test]
+    fn copy_buffer_edges() {
+        // Every source and destination is exactly as large as the bytes the copy is allowed to touch.
+        let x = [0b01011011u8, 0b10010110, 0b11100001];
+        for src_bit in 0..8usize {
+            for dst_bit in 0..8usize {
+                for bit_count in 0..=(24 - src_bit) {
+                    let src_len = (src_bit + bit_count).div_ceil(8);
+                    let dst_len = (dst_bit + bit_count).div_ceil(8);
+                    let src = x[..src_len].to_vec();
+                    let mut dst = vec![0b10101010u8; dst_len];
+
+                    let xptr = BitPtr::new(src.as_ptr(), SubByte::new(src_bit as u8).unwrap());
+                    let yptr = BitPtrMut::new(dst.as_mut_ptr(), SubByte::new(dst_bit as u8).unwrap());
+                    unsafe { copy_nonoverlapping(xptr, yptr, bit_count); }
+
+                    for i in 0..(dst_len * 8) {
+                        let expected = if (i >= dst_bit && i < dst_bit + bit_count) {
+                            let j = i - dst_bit + src_bit;
+                            (x[j / 8] << (j % 8)) & 0b10000000 != 0
+                        } else { (0b10101010u8 << (i % 8)) & 0b10000000 != 0 };
+                        assert_eq!(unsafe { BitPtr::new_on_byte(dst.as_ptr()).bit_offset(i as isize).read() }, expected);
+                    }
+                }
+            }
+        }
+    }
+
+
+
+    #[test]
+    fn copy_overlapping() {
+        let x = [0b01011011u8, 0b10010110, 0b11100001, 0b00111010];
+        for src_bit in 0..16usize {
+            for dst_bit in 0..16usize {
+                for bit_count in 0..=(32 - src_bit.max(dst_bit)) {
+                    let mut y = x;
+                    // Both pointers are derived from the same mutable pointer, so neither invalidates the other.
+                    let base = y.as_mut_ptr();
+                    let xptr = unsafe { BitPtr::new_with_offset(base.cast_const(), src_bit as isize) };
+                    let yptr = unsafe { BitPtrMut::new_with_offset(base, dst_bit as isize) };
+                    unsafe { copy(xptr, yptr, bit_count); }
+
+                    for i in 0..32 {
+                        let j = if (i >= dst_bit && i < dst_bit + bit_count) { i - dst_bit + src_bit } else { i };
+                        let expected = (x[j / 8] << (j % 8)) & 0b10000000 != 0;
+                        assert_eq!(unsafe { BitPtr::new_on_byte(y.as_ptr()).bit_offset(i as isize).read() }, expected);
+                    }
+                }
+            }
+        }
+    }
+
+
+
+    #[test]
+    fn copy_large_unaligned() {
+        let x = (0..300u32).map(|i| (i.wrapping_mul(0x9E37_79B9) >> 24) as u8).collect::<Vec<_>>();
+        for src_lsb in [false, true] {
+            for dst_lsb in [false, true] {
+                for src_bit in 0..8usize {
+                    for dst_bit in 0..8usize {
+                        for bit_count in [9, 63, 64, 65, 127, 128, 129, 1000, 2000] {
+                            let mut y    = vec![0b10101010u8; 300];
+                            let     xptr = BitPtr::new(x.as_ptr(), SubByte::new(src_bit as u8).unwrap());
+                            let     yptr = BitPtrMut::new(y.as_mut_ptr(), SubByte::new(dst_bit as u8).unwrap());
+                            unsafe { match (src_lsb, dst_lsb,) {
+                                (false, false,) => copy_nonoverlapping(xptr, yptr, bit_count),
+                                (false, true,)  => copy_nonoverlapping(xptr, yptr.cast_order::<Lsb0>(), bit_count),
+                                (true, false,)  => copy_nonoverlapping(xptr.cast_order::<Lsb0>(), yptr, bit_count),
+                                (true, true,)   => copy_nonoverlapping(xptr.cast_order::<Lsb0>(), yptr.cast_order::<Lsb0>(), bit_count)
+                            } }
+
+                            let x_at = |i : usize| if (src_lsb) { (x[i / 8] >> (i % 8)) & 1 != 0 } else { (x[i / 8] << (i % 8)) & 0b10000000 != 0 };
+                            let y_at = |i : usize| if (dst_lsb) { (y[i / 8] >> (i % 8)) & 1 != 0 } else { (y[i / 8] << (i % 8)) & 0b10000000 != 0 };
+                            for i in 0..(y.len() * 8) {
+                                if (i >= dst_bit && i < dst_bit + bit_count) {
+                                    assert_eq!(y_at(i), x_at(i - dst_bit + src_bit));
+                                } else {
+                                    assert_eq!(y_at(i), (i % 2 == 1) == dst_lsb);
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+
+
+    #[test]
+    fn copy_between_bit_orders() {
+        let x = [0b01011011u8, 0b10010110, 0b11100001];
+        for src_lsb in [false, true] {
+            for dst_lsb in [false, true] {
+                for src_bit in 0..8usize {
+                    for dst_bit in 0..8usize {
+                        let bit_count = 13;
+                        let mut y = [0b10101010u8; 3];
+                        let xptr = BitPtr::new(x.as_ptr(), SubByte::new(src_bit as u8).unwrap());
+                        let yptr = BitPtrMut::new(y.as_mut_ptr(), SubByte::new(dst_bit as u8).unwrap());
+                        unsafe { match (src_lsb, dst_lsb,) {
+                            (false, false,) => copy_nonoverlapping(xptr, yptr, bit_count),
+                            (false, true,)  => copy_nonoverlapping(xptr, yptr.cast_order::<Lsb0>(), bit_count),
+                            (true, false,)  => copy_nonoverlapping(xptr.cast_order::<Lsb0>(), yptr, bit_count),
+                            (true, true,)   => copy_nonoverlapping(xptr.cast_order::<Lsb0>(), yptr.cast_order::<Lsb0>(), bit_count)
+                        } }
+
+                        let x_at = |i : usize| if (src_lsb) { (x[i / 8] >> (i % 8)) & 1 != 0 } else { (x[i / 8] << (i % 8)) & 0b10000000 != 0 };
+                        let y_at = |i : usize| if (dst_lsb) { (y[i / 8] >> (i % 8)) & 1 != 0 } else { (y[i / 8] << (i % 8)) & 0b10000000 != 0 };
+                        for i in 0..24 {
+                            if (i >= dst_bit && i < dst_bit + bit_count) {
+                                assert_eq!(y_at(i), x_at(i - dst_bit + src_bit));
+                            } else {
+                                // Unchanged bits of `0b10101010`.
+                                assert_eq!(y_at(i), (i % 2 == 1) == dst_lsb);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
 }
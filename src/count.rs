@@ -0,0 +1,195 @@
+use crate::{ BitPtr, BitOrder };
+use crate::words::{ Words, word_mask };
+use crate::simd;
+
+
+/// Returns the number of `1` bits in the `bit_count` bits at `ptr`.
+///
+/// # Safety
+/// Behaviour is undefined if `ptr.floor_byte()` is not [valid](core::ptr#safety) for reads of `((ptr.subbyte_bit().get() as usize) + bit_count).div_ceil(8)` bytes.
+///
+/// # Examples
+/// ```rust
+/// use bitptr::BitPtr;
+///
+/// let x = [0b_01011011_u8, 0b_10010110_u8];
+/// //             ^^^^^^^^^^^ This is the region that is counted.
+///
+/// let xptr = unsafe { BitPtr::new_with_offset(x.as_ptr(), 3) };
+/// assert_eq!(unsafe { bitptr::count_ones(xptr, 11) }, 7);
+/// assert_eq!(unsafe { bitptr::count_zeros(xptr, 11) }, 4);
+/// ```
+pub unsafe fn count_ones<O : BitOrder>(ptr : BitPtr<O>, bit_count : usize) -> usize {
+    // The whole bytes after the first byte may be counted all at once. Bit order does not change the count.
+    let (byte, bit,) = ptr.as_inner();
+    let head_bits    = (8 - (bit.get() as usize)).min(bit_count);
+    let full_count   = (bit_count - head_bits) / 8;
+    if (full_count > 0) {
+        let (handled, ones,) = unsafe { simd::count_ones(byte.add(1), full_count) };
+        if (handled > 0) {
+            let skipped = head_bits + (handled * 8);
+            return unsafe { count_ones_chunked(ptr, head_bits) + ones + count_ones_chunked(ptr.bit_add(skipped), bit_count - skipped) };
+        }
+    }
+    unsafe { count_ones_chunked(ptr, bit_count) }
+}
+
+/// # Safety
+/// See [`count_ones`].
+#[inline(always)]
+unsafe fn count_ones_chunked<O : BitOrder>(ptr : BitPtr<O>, bit_count : usize) -> usize {
+    unsafe { Words::new(ptr, bit_count) }
+        .map(|word| word.count_ones() as usize)
+        .sum()
+}
+
+
+/// Returns the number of `0` bits in the `bit_count` bits at `ptr`.
+///
+/// # Safety
+/// See [`count_ones`].
+pub unsafe fn count_zeros<O : BitOrder>(ptr : BitPtr<O>, bit_count : usize) -> usize {
+    bit_count - unsafe { count_ones(ptr, bit_count) }
+}
+
+
+/// Returns the index of the first `1` bit in the `bit_count` bits at `ptr`.
+///
+/// # Returns
+/// Returns `None` if all of the bits are `0`.
+///
+/// # Safety
+/// See [`count_ones`].
+pub unsafe fn first_one<O : BitOrder>(ptr : BitPtr<O>, bit_count : usize) -> Option<usize> {
+    unsafe { first_match(ptr, bit_count, false) }
+}
+
+
+/// Returns the index of the first `0` bit in the `bit_count` bits at `ptr`.
+///
+/// # Returns
+/// Returns `None` if all of the bits are `1`.
+///
+/// # Safety
+/// See [`count_ones`].
+pub unsafe fn first_zero<O : BitOrder>(ptr : BitPtr<O>, bit_count : usize) -> Option<usize> {
+    unsafe { first_match(ptr, bit_count, true) }
+}
+
+
+/// Returns the index of the last `1` bit in the `bit_count` bits at `ptr`.
+///
+/// # Returns
+/// Returns `None` if all of the bits are `0`.
+///
+/// # Safety
+/// See [`count_ones`].
+pub unsafe fn last_one<O : BitOrder>(ptr : BitPtr<O>, bit_count : usize) -> Option<usize> {
+    unsafe { last_match(ptr, bit_count, false) }
+}
+
+
+/// Returns the index of the last `0` bit in the `bit_count` bits at `ptr`.
+///
+/// # Returns
+/// Returns `None` if all of the bits are `1`.
+///
+/// # Safety
+/// See [`count_ones`].
+pub unsafe fn last_zero<O : BitOrder>(ptr : BitPtr<O>, bit_count : usize) -> Option<usize> {
+    unsafe { last_match(ptr, bit_count, true) }
+}
+
+
+/// Returns the index of the first bit which is `1`, or `0` if `invert` is `true`.
+///
+/// # Safety
+/// See [`count_ones`].
+#[inline(always)]
+unsafe fn first_match<O : BitOrder>(ptr : BitPtr<O>, bit_count : usize, invert : bool) -> Option<usize> {
+    unsafe { Words::new(ptr, bit_count) }
+        .enumerate()
+        .find_map(|(index, word,)| {
+            let matches = if (invert) { (! word) & word_mask(bit_count, index) } else { word };
+            (matches != 0).then(|| (index * 64) + (matches.leading_zeros() as usize))
+        })
+}
+
+
+/// Returns the index of the last bit which is `1`, or `0` if `invert` is `true`.
+///
+/// # Safety
+/// See [`count_ones`].
+#[inline(always)]
+unsafe fn last_match<O : BitOrder>(ptr : BitPtr<O>, bit_count : usize, invert : bool) -> Option<usize> {
+    unsafe { Words::new(ptr, bit_count) }
+        .enumerate()
+        .rev()
+        .find_map(|(index, word,)| {
+            let matches = if (invert) { (! word) & word_mask(bit_count, index) } else { word };
+            (matches != 0).then(|| (index * 64) + 63 - (matches.trailing_zeros() as usize))
+        })
+}
+
+
+/// Returns the number of `0` bits before the first `1` bit in the `bit_count` bits at `ptr`.
+///
+/// # Safety
+/// See [`count_ones`].
+pub unsafe fn leading_zeros<O : BitOrder>(ptr : BitPtr<O>, bit_count : usize) -> usize {
+    unsafe { first_one(ptr, bit_count) }.unwrap_or(bit_count)
+}
+
+
+/// Returns the number of `0` bits after the last `1` bit in the `bit_count` bits at `ptr`.
+///
+/// # Safety
+/// See [`count_ones`].
+pub unsafe fn trailing_zeros<O : BitOrder>(ptr : BitPtr<O>, bit_count : usize) -> usize {
+    unsafe { last_one(ptr, bit_count) }.map_or(bit_count, |index| bit_count - index - 1)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Lsb0;
+
+
+    const X : [u8; 20] = [
+        0b01011011, 0b10010110, 0b11100001, 0b00111100, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000,
+        0b11111111, 0b11111111, 0b11111111, 0b11111111, 0b11111111, 0b11111111, 0b11111111, 0b11111111, 0b10101010, 0b01010101
+    ];
+
+
+    #[test]
+    fn count_scan_unaligned() {
+        let xptr = BitPtr::new_on_byte(X.as_ptr());
+        for start in 0..24 {
+            for end in start..=(X.len() * 8) {
+                let ptr   = unsafe { xptr.bit_add(start) };
+                let len   = end - start;
+                let model = (0..len).map(|i| unsafe { ptr.bit_add(i).read() }).collect::<Vec<_>>();
+                assert_eq!(unsafe { count_ones(ptr, len) }, model.iter().filter(|&&b| b).count());
+                assert_eq!(unsafe { count_zeros(ptr, len) }, model.iter().filter(|&&b| ! b).count());
+                assert_eq!(unsafe { first_one(ptr, len) }, model.iter().position(|&b| b));
+                assert_eq!(unsafe { first_zero(ptr, len) }, model.iter().position(|&b| ! b));
+                assert_eq!(unsafe { last_one(ptr, len) }, model.iter().rposition(|&b| b));
+                assert_eq!(unsafe { last_zero(ptr, len) }, model.iter().rposition(|&b| ! b));
+                assert_eq!(unsafe { leading_zeros(ptr, len) }, model.iter().take_while(|&&b| ! b).count());
+                assert_eq!(unsafe { trailing_zeros(ptr, len) }, model.iter().rev().take_while(|&&b| ! b).count());
+            }
+        }
+    }
+
+
+    #[test]
+    fn count_scan_lsb0() {
+        let xptr = BitPtr::new_on_byte(X.as_ptr()).cast_order::<Lsb0>();
+        assert_eq!(unsafe { first_one(xptr.bit_add(2), 14) }, Some(1));
+        assert_eq!(unsafe { first_zero(xptr, 16) }, Some(2));
+        assert_eq!(unsafe { last_one(xptr, 14) }, Some(12));
+        assert_eq!(unsafe { count_ones(xptr.bit_add(4), 12) }, 6);
+    }
+
+}
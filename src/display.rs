@@ -0,0 +1,99 @@
+use crate::{ BitPtr, BitOrder, Msb0 };
+use core::fmt;
+
+
+/// Renders a range of bits in binary, for debugging.
+///
+/// Every byte which contains a bit of the range is rendered in full, as a group of 8 bits.
+///  Bits are rendered in the order of their sub-byte bit offsets, so with [`Lsb0`](crate::Lsb0) bit order the least significant bit of each byte comes first.
+///  A second line holds a caret under the first bit of the range.
+///
+/// # Safety
+/// Behaviour is undefined if `ptr.floor_byte()` is not [valid](core::ptr#safety) for reads of `((ptr.subbyte_bit().get() as usize) + bit_count).div_ceil(8)` bytes
+///  whenever the returned value is formatted.
+///
+/// # Examples
+/// ```rust
+/// use bitptr::BitPtr;
+///
+/// let x = [0b_01011011_u8, 0b_10010110_u8, 0b_11100001_u8];
+///
+/// let xptr = unsafe { BitPtr::new_with_offset(x.as_ptr(), 11) };
+/// assert_eq!(
+///     unsafe { bitptr::display_bits(xptr, 7) }.to_string(),
+///     "10010110 11100001\n   ^"
+/// );
+/// ```
+#[inline]
+pub unsafe fn display_bits<O : BitOrder>(ptr : BitPtr<O>, bit_count : usize) -> DisplayBits<O> {
+    DisplayBits { ptr, bit_count }
+}
+
+
+/// A range of bits which can be rendered with [`Display`](fmt::Display).
+///
+/// Created by [`display_bits`].
+#[derive(Clone, Copy)]
+pub struct DisplayBits<O : BitOrder = Msb0> {
+    ptr       : BitPtr<O>,
+    bit_count : usize
+}
+
+impl<O : BitOrder> fmt::Display for DisplayBits<O> {
+    fn fmt(&self, f : &mut fmt::Formatter<'_>) -> fmt::Result {
+        if (self.bit_count == 0) { return Ok(()); }
+        let (byte, bit,) = self.ptr.as_inner();
+        let byte_count   = ((bit.get() as usize) + self.bit_count).div_ceil(8);
+        let first        = BitPtr::new_on_byte(byte).cast_order::<O>();
+        for offset in 0..byte_count {
+            if (offset > 0) { f.write_str(" ")?; }
+            for i in 0..8 {
+                let value = unsafe { first.bit_add((offset * 8) + i).read() };
+                f.write_str(if (value) { "1" } else { "0" })?;
+            }
+        }
+        write!(f, "\n{:>width$}", "^", width = (bit.get() as usize) + 1)
+    }
+}
+
+impl<O : BitOrder> fmt::Debug for DisplayBits<O> {
+    fn fmt(&self, f : &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Lsb0;
+
+
+    #[test]
+    fn display_bits_range() {
+        let x = [0b01011011u8, 0b10010110, 0b11100001];
+
+        let xptr = BitPtr::new_on_byte(x.as_ptr());
+        assert_eq!(unsafe { display_bits(xptr, 0) }.to_string(), "");
+        assert_eq!(unsafe { display_bits(xptr, 1) }.to_string(), "01011011\n^");
+        assert_eq!(unsafe { display_bits(xptr.bit_add(7), 2) }.to_string(), "01011011 10010110\n       ^");
+        assert_eq!(unsafe { display_bits(xptr.bit_add(8), 16) }.to_string(), "10010110 11100001\n^");
+        assert_eq!(unsafe { display_bits(xptr.cast_order::<Lsb0>().bit_add(2), 3) }.to_string(), "11011010\n  ^");
+    }
+
+
+    #[test]
+    fn bitptr_fmt() {
+        let x = [0u8; 2];
+
+        let xptr = unsafe { BitPtr::new_with_offset(x.as_ptr(), 13) };
+        let addr = x.as_ptr().addr() + 1;
+        assert_eq!(format!("{:?}", xptr), format!("{:#x}.5", addr));
+        assert_eq!(format!("{:p}", xptr.as_mut()), format!("{:#x}.5", addr));
+        assert_eq!(format!("{:b}", xptr), format!("{:b}.101", addr));
+        assert_eq!(format!("{:#b}", xptr.cast_order::<Lsb0>()), format!("{:#b}.101", addr));
+        assert_eq!(format!("{:?}", xptr.subbyte_bit()), "SubByte(5)");
+        assert_eq!(format!("{:?}", crate::NonNullBitPtr::new(xptr.as_mut()).unwrap()), format!("{:#x}.5", addr));
+    }
+
+}
@@ -1,4 +1,6 @@
-use crate::BitPtrMut;
+use crate::{ BitPtr, BitPtrMut, BitOrder };
+use crate::order::reorder;
+use crate::simd;
 
 
 /// Fills `bit_count` bits at `dst`.
@@ -7,7 +9,121 @@ use crate::BitPtrMut;
 ///
 /// # Safety
 /// Behaviour is undefined if `src.floor_byte()` is not [valid](core::ptr#safety) for writes of `((dst.subbyte_bit().get() as usize) + bit_count).div_ceil(8)` bytes.
-pub unsafe fn fill(dst : BitPtrMut, bit_count : usize, value : bool) {
+pub unsafe fn fill<O : BitOrder>(dst : BitPtrMut<O>, bit_count : usize, value : bool) {
+    unsafe { fill_impl(dst, bit_count, value, false); }
+}
+
+
+/// Fills `bit_count` bits at `dst`, using volatile reads and writes.
+///
+/// Only the bytes which are partially covered by the bit range are read. Each byte is read at most once, and written exactly once.
+///  See [`fill`].
+///
+/// # Safety
+/// Behaviour is undefined if `src.floor_byte()` is not [valid](core::ptr#safety) for reads and writes of `((dst.subbyte_bit().get() as usize) + bit_count).div_ceil(8)` bytes.
+pub unsafe fn fill_volatile<O : BitOrder>(dst : BitPtrMut<O>, bit_count : usize, value : bool) {
+    unsafe { fill_impl(dst, bit_count, value, true); }
+}
+
+
+/// Fills `bit_count` bits at `dst` with copies of the `pattern_len` bits at `pattern`, repeated end to end.
+///
+/// The last copy of the pattern is cut short if `bit_count` is not a multiple of `pattern_len`.
+///  `pattern` and `dst` may use different [bit orders](BitOrder).
+///
+/// # Safety
+/// Behaviour is undefined if any of the following conditions are violated:
+/// - `pattern.floor_byte()` must be [valid](core::ptr#safety) for reads of `((pattern.subbyte_bit().get() as usize) + pattern_len).div_ceil(8)` bytes.
+/// - `dst.floor_byte()` must be [valid](core::ptr#safety) for reads and writes of `((dst.subbyte_bit().get() as usize) + bit_count).div_ceil(8)` bytes.
+/// - The region of memory beginning at `pattern` with a size of `pattern_len` bits must *not* overlap with the region of memory beginning at `dst` with a size of `bit_count` bits.
+///
+/// # Panics
+/// Panics if `pattern_len` is `0` and `bit_count` is not.
+///
+/// # Examples
+/// ```rust
+/// use bitptr::{ BitPtr, BitPtrMut };
+///
+/// let     x = [0b_10100000_u8];
+/// let mut y = [0u8; 2];
+///
+/// let xptr = BitPtr::new_on_byte(x.as_ptr());
+/// let yptr = unsafe { BitPtrMut::new_with_offset(y.as_mut_ptr(), 2) };
+///
+/// unsafe { bitptr::fill_pattern(yptr, 13, xptr, 3); }
+/// assert_eq!(y, [0b_00101101_u8, 0b_10110110_u8]);
+/// ```
+pub unsafe fn fill_pattern<P : BitOrder, O : BitOrder>(dst : BitPtrMut<O>, bit_count : usize, pattern : BitPtr<P>, pattern_len : usize) {
+    if (bit_count == 0) { return; }
+    assert!(pattern_len > 0, "pattern length is zero");
+    let first = pattern_len.min(bit_count);
+    unsafe { crate::copy_nonoverlapping(pattern, dst, first); }
+    unsafe { tile(dst, bit_count, first); }
+}
+
+
+/// Fills `bit_count` bits at `dst` with copies of the lowest `pattern_bits` bits of `pattern`, repeated end to end.
+///
+/// The bits of the pattern are written most significant first, regardless of the bit order `O`.
+///  That is, the `i`th bit at `dst` is bit `pattern_bits - 1 - (i % pattern_bits)` of `pattern`.
+///  The last copy of the pattern is cut short if `bit_count` is not a multiple of `pattern_bits`.
+///
+/// # Safety
+/// Behaviour is undefined if `dst.floor_byte()` is not [valid](core::ptr#safety) for reads and writes of `((dst.subbyte_bit().get() as usize) + bit_count).div_ceil(8)` bytes.
+///
+/// # Panics
+/// Panics if `pattern_bits` is greater than `64`, or if `pattern_bits` is `0` and `bit_count` is not.
+///
+/// # Examples
+/// ```rust
+/// use bitptr::BitPtrMut;
+///
+/// let mut x = [0u8; 3];
+///
+/// let xptr = unsafe { BitPtrMut::new_with_offset(x.as_mut_ptr(), 4) };
+///
+/// unsafe { bitptr::fill_with_u64(xptr, 20, 0b10, 2); }
+/// assert_eq!(x, [0b_00001010_u8, 0b_10101010_u8, 0b_10101010_u8]);
+/// ```
+pub unsafe fn fill_with_u64<O : BitOrder>(dst : BitPtrMut<O>, bit_count : usize, pattern : u64, pattern_bits : u32) {
+    assert!(pattern_bits <= 64, "pattern width is greater than the size of the integer type");
+    if (bit_count == 0) { return; }
+    assert!(pattern_bits > 0, "pattern width is zero");
+
+    // Repeat the pattern as many times as fits in a word, so that the first write is as wide as possible.
+    let pattern   = if (pattern_bits == 64) { pattern } else { pattern & ((1 << pattern_bits) - 1) };
+    let repeats   = 64 / pattern_bits;
+    let mut word  = 0u64;
+    for _ in 0..repeats {
+        word = word.checked_shl(pattern_bits).unwrap_or(0) | pattern;
+    }
+    let word_bits = repeats * pattern_bits;
+
+    // `write_bits` writes the least significant bit first under `Lsb0` orders, so reverse the bits to match the tiling order.
+    let first = (word_bits as usize).min(bit_count) as u32;
+    let value = word >> (word_bits - first);
+    let value = if (O::LSB_FIRST) { value.reverse_bits() >> (64 - first) } else { value };
+    unsafe { dst.write_bits(value, first); }
+    unsafe { tile(dst, bit_count, first as usize); }
+}
+
+
+/// Repeats the first `filled` bits at `dst` until `bit_count` bits are filled, doubling the filled region each time.
+///
+/// # Safety
+/// See [`fill_with_u64`].
+#[inline(always)]
+unsafe fn tile<O : BitOrder>(dst : BitPtrMut<O>, bit_count : usize, mut filled : usize) {
+    while (filled < bit_count) {
+        let n = filled.min(bit_count - filled);
+        unsafe { crate::copy_nonoverlapping(dst.as_const(), dst.bit_add(filled), n); }
+        filled += n;
+    }
+}
+
+
+#[inline(always)]
+unsafe fn fill_impl<O : BitOrder>(dst : BitPtrMut<O>, bit_count : usize, value : bool, volatile : bool) {
     if (bit_count == 0) { return; }
 
     let (dst_byte, dst_bit,) = dst.as_inner();
@@ -15,7 +131,20 @@ pub unsafe fn fill(dst : BitPtrMut, bit_count : usize, value : bool) {
     let dst_bit_r = (8isize - ((dst_bit_l + bit_count) as isize)).rem_euclid(8);
 
     let dst_byte_count = (dst_bit_l + bit_count).div_ceil(8);
-    for dst_offset in 0..dst_byte_count {
+
+    // The whole bytes in the middle of the range may be filled all at once.
+    let full_start = if (dst_bit_l == 0) { 0 } else { 1 };
+    let full_end   = if (dst_bit_r == 0) { dst_byte_count } else { dst_byte_count - 1 };
+    let handled    = if (volatile || full_end <= full_start) { 0 } else {
+        unsafe { simd::fill(dst_byte.byte_add(full_start), full_end - full_start, if (value) { u8::MAX } else { 0 }) }
+    };
+
+    let mut dst_offset = 0;
+    while (dst_offset < dst_byte_count) {
+        if (handled > 0 && dst_offset == full_start) {
+            dst_offset += handled;
+            continue;
+        }
         let dst_byte = unsafe { dst_byte.byte_add(dst_offset) };
 
         // Get a mask over the bits to write.
@@ -26,14 +155,19 @@ pub unsafe fn fill(dst : BitPtrMut, bit_count : usize, value : bool) {
         if (dst_offset + 1 == dst_byte_count) {
             mask = mask >> dst_bit_r << dst_bit_r;
         }
+        let mask = reorder::<O>(mask);
 
         // Fill the relevant bit range.
-        if (value) {
+        if (volatile) {
+            let old = if (mask == u8::MAX) { 0 } else { unsafe { dst_byte.read_volatile() } };
+            unsafe { dst_byte.write_volatile(if (value) { old | mask } else { old & ! mask }); }
+        } else if (value) {
             unsafe { *dst_byte |= mask; }
         } else {
             unsafe { *dst_byte &= ! mask; }
         }
 
+        dst_offset += 1;
     }
 }
 
@@ -41,6 +175,7 @@ pub unsafe fn fill(dst : BitPtrMut, bit_count : usize, value : bool) {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{ Lsb0, Msb0 };
 
 
     #[test]
@@ -114,4 +249,90 @@ mod tests {
         assert_eq!(y, 0b0101101110010111u16);
     }
 
+
+    #[test]
+    fn fill_lsb0() {
+        let mut x = 0b0101101110010110u16.to_le();
+
+        let xptr = unsafe { BitPtrMut::new_on_byte(&mut x as *mut _ as *mut _).cast_order::<Lsb0>().bit_offset(3) };
+
+        // Fill and check final value.
+        unsafe { fill(xptr, 7, true); }
+        assert_eq!(u16::from_le(x), 0b0101101111111110u16);
+        unsafe { fill(xptr, 7, false); }
+        assert_eq!(u16::from_le(x), 0b0101100000000110u16);
+    }
+
+
+    #[test]
+    fn fill_volatile_register_block() {
+        // A plain-memory stand-in for a block of memory-mapped registers.
+        #[repr(C)]
+        struct Registers { control : u8, mask : [u8; 3], status : u8 }
+        let mut regs = Registers { control : 0b10100101, mask : [0b01011011, 0b10010110, 0b11100001], status : 0b00000000 };
+
+        let mask = BitPtrMut::new_on_byte((&raw mut regs.mask).cast::<u8>());
+        unsafe { fill_volatile(mask.bit_add(3), 18, true); }
+        assert_eq!(regs.mask, [0b01011111, 0b11111111, 0b11111001]);
+        unsafe { fill_volatile(mask.bit_add(8), 8, false); }
+        unsafe { fill_volatile(mask.cast_order::<Lsb0>().bit_add(16), 2, false); }
+        assert_eq!(regs.mask, [0b01011111, 0b00000000, 0b11111000]);
+        assert_eq!(regs.control, 0b10100101);
+        assert_eq!(regs.status, 0b00000000);
+    }
+
+
+    #[test]
+    fn fill_pattern_unaligned() {
+        let pattern = [0b01011011u8, 0b10010110, 0b11100001];
+        for dst_bit in 0..8 {
+            for pattern_bit in [0, 3] {
+                for pattern_len in [1, 2, 3, 7, 12, 17] {
+                    for bit_count in [0, 1, 5, 12, 64, 100, 300] {
+                        let mut x    = [0b10101010u8; 48];
+                        let     xptr = unsafe { BitPtrMut::new_on_byte(x.as_mut_ptr()).cast_order::<Lsb0>().bit_add(dst_bit) };
+                        let     pptr = unsafe { BitPtr::new_on_byte(pattern.as_ptr()).bit_add(pattern_bit) };
+                        unsafe { fill_pattern(xptr, bit_count, pptr, pattern_len); }
+
+                        let old = [0b10101010u8; 48];
+                        let old = BitPtr::new_on_byte(old.as_ptr()).cast_order::<Lsb0>();
+                        for i in 0..(x.len() * 8) {
+                            let expected = if (i >= dst_bit && i < dst_bit + bit_count) {
+                                unsafe { pptr.bit_add((i - dst_bit) % pattern_len).read() }
+                            } else { unsafe { old.bit_add(i).read() } };
+                            assert_eq!(unsafe { xptr.as_const().bit_sub(dst_bit).bit_add(i).read() }, expected);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+
+    #[test]
+    fn fill_with_u64_template() {
+        fn check<O : BitOrder>() {
+            let pattern = 0xA5C3_96F0_1E2D_4B78u64;
+            for pattern_bits in [1, 2, 12, 63, 64] {
+                for bit_count in (1..=64).chain([300]) {
+                    let mut x    = [0u8; 40];
+                    let     xptr = unsafe { BitPtrMut::new_with_offset(x.as_mut_ptr(), 5).cast_order::<O>() };
+                    unsafe { fill_with_u64(xptr, bit_count, pattern, pattern_bits); }
+                    for i in 0..(x.len() * 8 - 5) {
+                        let expected = (i < bit_count) && {
+                            let bit = (pattern_bits as usize) - 1 - (i % (pattern_bits as usize));
+                            (pattern >> bit) & 1 != 0
+                        };
+                        assert_eq!(unsafe { xptr.as_const().bit_add(i).read() }, expected);
+                    }
+                    for i in 0..5 {
+                        assert_eq!(unsafe { xptr.as_const().bit_sub(i + 1).read() }, false);
+                    }
+                }
+            }
+        }
+        check::<Msb0>();
+        check::<Lsb0>();
+    }
+
 }
@@ -0,0 +1,264 @@
+use crate::{ SubByte, BitOrder };
+use crate::order::reorder;
+
+
+mod private {
+    pub trait Sealed { }
+}
+
+
+/// An unsigned integer type which can be read from and written to a bit field.
+///
+/// Implemented for [`u8`], [`u16`], [`u32`], [`u64`], [`u128`], and [`usize`].
+pub trait Unsigned : Copy + private::Sealed {
+
+    /// The size of this integer type in bits.
+    const BITS : u32;
+
+    #[doc(hidden)]
+    fn from_u128(value : u128) -> Self;
+
+    #[doc(hidden)]
+    fn into_u128(self) -> u128;
+
+}
+
+macro_rules! impl_unsigned { ( $( $ty:ty ),* $(,)? ) => { $(
+
+    impl private::Sealed for $ty { }
+
+    impl Unsigned for $ty {
+        const BITS : u32 = <$ty>::BITS;
+        #[inline(always)]
+        fn from_u128(value : u128) -> Self { value as $ty }
+        #[inline(always)]
+        fn into_u128(self) -> u128 { self as u128 }
+    }
+
+)* } }
+
+impl_unsigned!(u8, u16, u32, u64, u128, usize);
+
+
+/// A signed integer type which can be read from and written to a two's complement bit field.
+///
+/// Implemented for [`i8`], [`i16`], [`i32`], [`i64`], [`i128`], and [`isize`].
+pub trait Signed : Copy + private::Sealed {
+
+    /// The size of this integer type in bits.
+    const BITS : u32;
+
+    #[doc(hidden)]
+    fn from_i128(value : i128) -> Self;
+
+    #[doc(hidden)]
+    fn into_i128(self) -> i128;
+
+}
+
+macro_rules! impl_signed { ( $( $ty:ty ),* $(,)? ) => { $(
+
+    impl private::Sealed for $ty { }
+
+    impl Signed for $ty {
+        const BITS : u32 = <$ty>::BITS;
+        #[inline(always)]
+        fn from_i128(value : i128) -> Self { value as $ty }
+        #[inline(always)]
+        fn into_i128(self) -> i128 { self as i128 }
+    }
+
+)* } }
+
+impl_signed!(i8, i16, i32, i64, i128, isize);
+
+
+/// Sign-extends the lowest `width` bits of `value`.
+///
+/// `width` must be at most `128`. A `width` of `0` always results in `0`.
+#[inline]
+pub(crate) const fn sign_extend(value : u128, width : u32) -> i128 {
+    if (width == 0) { return 0; }
+    let shift = 128 - width;
+    ((value << shift) as i128) >> shift
+}
+
+
+/// Returns `true` if `value` can be represented as a `width`-bit two's complement integer.
+///
+/// `width` must be at most `128`.
+#[inline]
+pub(crate) const fn fits_signed(value : i128, width : u32) -> bool {
+    sign_extend(value as u128, width) == value
+}
+
+
+/// Reads a `width`-bit unsigned integer, in the bit order `O`.
+///
+/// # Safety
+/// Behaviour is undefined if `byte` is not [valid](core::ptr#safety) for reads of `((bit.get() as usize) + width).div_ceil(8)` bytes,
+///  or if `width` is greater than `128`.
+pub(crate) unsafe fn read_u128<O : BitOrder>(byte : *const u8, bit : SubByte, width : u32) -> u128 {
+    if (width == 0) { return 0; }
+
+    let bit_l      = bit.get() as u32;
+    if (bit_l + width <= 64) {
+        // The field fits in one word, so extract it with a single shift.
+        let word = unsafe { load_word::<O>(byte, bit_l + width) };
+        return reorder_u64::<O>((word << bit_l) >> (64 - width), width) as u128;
+    }
+
+    let byte_count = (bit_l + width).div_ceil(8) as usize;
+    let bit_r      = ((byte_count as u32) * 8) - bit_l - width;
+
+    // Only the bits after `bit_l` in the first byte are part of the value.
+    let mut value = (reorder::<O>(unsafe { *byte }) & (u8::MAX >> bit_l)) as u128;
+    if (byte_count == 1) {
+        return reorder_u128::<O>(value >> bit_r, width);
+    }
+
+    for offset in 1..(byte_count - 1) {
+        value = (value << 8) | (reorder::<O>(unsafe { *byte.add(offset) }) as u128);
+    }
+
+    // Only the bits before `bit_r` in the last byte are part of the value.
+    let last = reorder::<O>(unsafe { *byte.add(byte_count - 1) });
+    reorder_u128::<O>((value << (8 - bit_r)) | ((last >> bit_r) as u128), width)
+}
+
+
+/// Writes the lowest `width` bits of `value`, in the bit order `O`.
+///
+/// # Safety
+/// Behaviour is undefined if `byte` is not [valid](core::ptr#safety) for reads and writes of `((bit.get() as usize) + width).div_ceil(8)` bytes,
+///  or if `width` is greater than `128`.
+pub(crate) unsafe fn write_u128<O : BitOrder>(byte : *mut u8, bit : SubByte, value : u128, width : u32) {
+    if (width == 0) { return; }
+
+    let bit_l      = bit.get() as u32;
+    if (bit_l + width <= 64) {
+        // The field fits in one word, so insert it with a single mask.
+        let end   = bit_l + width;
+        let shift = 64 - end;
+        let mask  = (u64::MAX >> (64 - width)) << shift;
+        let word  = unsafe { load_word::<O>(byte, end) };
+        let word  = (word & ! mask) | ((reorder_u64::<O>(value as u64, width) << shift) & mask);
+        unsafe { store_word::<O>(byte, end, word); }
+        return;
+    }
+    let mut value = reorder_u128::<O>(value, width);
+
+    let byte_count = (bit_l + width).div_ceil(8) as usize;
+    let bit_r      = ((byte_count as u32) * 8) - bit_l - width;
+
+    let mask_l = u8::MAX >> bit_l;
+    let mask_r = u8::MAX << bit_r;
+    if (byte_count == 1) {
+        let mask = mask_l & mask_r;
+        unsafe { *byte = reorder::<O>((reorder::<O>(*byte) & ! mask) | (((value as u8) << bit_r) & mask)); }
+        return;
+    }
+
+    // Write the last byte, which only contains the bits before `bit_r`.
+    let last = unsafe { byte.add(byte_count - 1) };
+    unsafe { *last = reorder::<O>((reorder::<O>(*last) & ! mask_r) | ((value as u8) << bit_r)); }
+    value >>= 8 - bit_r;
+
+    // Write the full bytes in between, from back to front.
+    for offset in (1..(byte_count - 1)).rev() {
+        unsafe { *byte.add(offset) = reorder::<O>(value as u8); }
+        value >>= 8;
+    }
+
+    // Write the first byte, which only contains the bits after `bit_l`.
+    unsafe { *byte = reorder::<O>((reorder::<O>(*byte) & ! mask_l) | ((value as u8) & mask_l)); }
+}
+
+
+/// Loads the `bit_count.div_ceil(8)` bytes at `byte` into the most significant end of a word, in [`Msb0`](crate::Msb0) bit order.
+///
+/// Only those bytes are read, so the field can end at the end of an allocation.
+///
+/// # Safety
+/// Behaviour is undefined if `byte` is not [valid](core::ptr#safety) for reads of `bit_count.div_ceil(8)` bytes,
+///  or if `bit_count` is greater than `64`.
+#[inline(always)]
+unsafe fn load_word<O : BitOrder>(byte : *const u8, bit_count : u32) -> u64 {
+    let mut buf = [0u8; 8];
+    unsafe { core::ptr::copy_nonoverlapping(byte, buf.as_mut_ptr(), bit_count.div_ceil(8) as usize); }
+    crate::order::reorder_u64::<O>(u64::from_be_bytes(buf))
+}
+
+/// Stores the most significant `bit_count.div_ceil(8)` bytes of a word loaded by [`load_word`].
+///
+/// # Safety
+/// Behaviour is undefined if `byte` is not [valid](core::ptr#safety) for writes of `bit_count.div_ceil(8)` bytes,
+///  or if `bit_count` is greater than `64`.
+#[inline(always)]
+unsafe fn store_word<O : BitOrder>(byte : *mut u8, bit_count : u32, word : u64) {
+    let buf = crate::order::reorder_u64::<O>(word).to_be_bytes();
+    unsafe { core::ptr::copy_nonoverlapping(buf.as_ptr(), byte, bit_count.div_ceil(8) as usize); }
+}
+
+
+/// The most bytes that a bit field of up to `128` bits can span.
+const MAX_FIELD_BYTES : usize = 17;
+
+
+/// Reads a `width`-bit unsigned integer, in the bit order `O`, using volatile reads.
+///
+/// Each byte of the field is read exactly once. If `width` is `0`, no bytes are read.
+///
+/// # Safety
+/// See [`read_u128`].
+pub(crate) unsafe fn read_u128_volatile<O : BitOrder>(byte : *const u8, bit : SubByte, width : u32) -> u128 {
+    if (width == 0) { return 0; }
+    let byte_count = ((bit.get() as usize) + (width as usize)).div_ceil(8);
+    let mut buf    = [0u8; MAX_FIELD_BYTES];
+    for (offset, b,) in buf[..byte_count].iter_mut().enumerate() {
+        *b = unsafe { byte.add(offset).read_volatile() };
+    }
+    unsafe { read_u128::<O>(buf.as_ptr(), bit, width) }
+}
+
+
+/// Writes the lowest `width` bits of `value`, in the bit order `O`, using volatile reads and writes.
+///
+/// Only the bytes which are partially covered by the field are read. Each byte of the field is read at most once, and written exactly once. If `width` is `0`, no bytes are read or written.
+///
+/// # Safety
+/// See [`write_u128`].
+pub(crate) unsafe fn write_u128_volatile<O : BitOrder>(byte : *mut u8, bit : SubByte, value : u128, width : u32) {
+    if (width == 0) { return; }
+    let bit_l      = bit.get() as usize;
+    let end        = bit_l + (width as usize);
+    let byte_count = end.div_ceil(8);
+    let mut buf    = [0u8; MAX_FIELD_BYTES];
+    if (bit_l != 0 || (byte_count == 1 && ! end.is_multiple_of(8))) {
+        buf[0] = unsafe { byte.read_volatile() };
+    }
+    if (byte_count > 1 && ! end.is_multiple_of(8)) {
+        buf[byte_count - 1] = unsafe { byte.add(byte_count - 1).read_volatile() };
+    }
+    unsafe { write_u128::<O>(buf.as_mut_ptr(), bit, value, width); }
+    for (offset, b,) in buf[..byte_count].iter().enumerate() {
+        unsafe { byte.add(offset).write_volatile(*b); }
+    }
+}
+
+
+/// Converts the lowest `width` bits of `value` between most significant bit first order, and the bit order `O`.
+///
+/// `width` must be between `1` and `64`.
+#[inline(always)]
+const fn reorder_u64<O : BitOrder>(value : u64, width : u32) -> u64 {
+    if (O::LSB_FIRST) { (value << (64 - width)).reverse_bits() } else { value }
+}
+
+/// Converts the lowest `width` bits of `value` between most significant bit first order, and the bit order `O`.
+///
+/// `width` must be between `1` and `128`.
+#[inline(always)]
+const fn reorder_u128<O : BitOrder>(value : u128, width : u32) -> u128 {
+    if (O::LSB_FIRST) { (value << (128 - width)).reverse_bits() } else { value }
+}
@@ -0,0 +1,305 @@
+//! Iterators over the bits of a [`BitSlice`].
+
+use crate::{ BitPtr, BitOrder, Msb0, BitSlice };
+use crate::order::reorder;
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+
+
+/// An iterator over the bits of a [`BitSlice`].
+///
+/// Created by [`BitSlice::iter`].
+#[derive(Clone)]
+pub struct Iter<'a, O : BitOrder = Msb0> {
+    byte        : *const u8,
+    bit_l       : usize,
+    front       : usize,
+    back        : usize,
+    front_cache : u8,
+    back_cache  : u8,
+    bytes       : PhantomData<&'a [u8]>,
+    order       : PhantomData<O>
+}
+
+impl<'a, O : BitOrder> Iter<'a, O> {
+
+    #[inline]
+    pub(crate) fn new(bits : BitSlice<'a, O>) -> Self {
+        let (byte, bit,) = bits.as_bitptr().as_inner();
+        let bit_l = bit.get() as usize;
+        let mut iter = Self {
+            byte,
+            bit_l,
+            front       : 0,
+            back        : bits.len(),
+            front_cache : 0,
+            back_cache  : 0,
+            bytes       : PhantomData,
+            order       : PhantomData
+        };
+        if (! bits.is_empty()) {
+            iter.front_cache = unsafe { iter.load(bit_l / 8) };
+            iter.back_cache  = unsafe { iter.load((bit_l + bits.len() - 1) / 8) };
+        }
+        iter
+    }
+
+    /// Returns the remaining bits as a bit slice.
+    #[inline]
+    pub fn as_bitslice(&self) -> BitSlice<'a, O> {
+        unsafe { BitSlice::from_raw_parts(
+            BitPtr::new_on_byte(self.byte).cast_order::<O>().bit_add(self.bit_l + self.front),
+            self.back - self.front
+        ) }
+    }
+
+    /// Reads a byte, in [`Msb0`] order.
+    #[inline(always)]
+    unsafe fn load(&self, offset : usize) -> u8 {
+        reorder::<O>(unsafe { *self.byte.add(offset) })
+    }
+
+}
+
+impl<O : BitOrder> Iterator for Iter<'_, O> {
+    type Item = bool;
+
+    #[inline]
+    fn next(&mut self) -> Option<bool> {
+        if (self.front >= self.back) { return None; }
+        let bit = self.bit_l + self.front;
+        if (bit.is_multiple_of(8)) {
+            self.front_cache = unsafe { self.load(bit / 8) };
+        }
+        self.front += 1;
+        Some(((self.front_cache << (bit % 8)) & 0b10000000) != 0)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>,) {
+        let len = self.back - self.front;
+        (len, Some(len),)
+    }
+
+}
+
+impl<O : BitOrder> DoubleEndedIterator for Iter<'_, O> {
+
+    #[inline]
+    fn next_back(&mut self) -> Option<bool> {
+        if (self.front >= self.back) { return None; }
+        self.back -= 1;
+        let bit = self.bit_l + self.back;
+        if (bit % 8 == 7) {
+            self.back_cache = unsafe { self.load(bit / 8) };
+        }
+        Some(((self.back_cache << (bit % 8)) & 0b10000000) != 0)
+    }
+
+}
+
+impl<O : BitOrder> ExactSizeIterator for Iter<'_, O> { }
+
+impl<O : BitOrder> FusedIterator for Iter<'_, O> { }
+
+
+macro_rules! iter_indices { (
+    $( #[doc = $doc:tt] )*
+    $ident:ident, $value:expr
+) => {
+
+    $( #[doc = $doc] )*
+    #[derive(Clone)]
+    pub struct $ident<'a, O : BitOrder = Msb0> {
+        byte      : *const u8,
+        bit_l     : usize,
+        front     : usize,
+        back      : usize,
+        remaining : usize,
+        bytes     : PhantomData<&'a [u8]>,
+        order     : PhantomData<O>
+    }
+
+    impl<'a, O : BitOrder> $ident<'a, O> {
+
+        #[inline]
+        pub(crate) fn new(bits : BitSlice<'a, O>) -> Self {
+            let (byte, bit,) = bits.as_bitptr().as_inner();
+            let remaining    = if ($value) {
+                unsafe { crate::count_ones(bits.as_bitptr(), bits.len()) }
+            } else {
+                unsafe { crate::count_zeros(bits.as_bitptr(), bits.len()) }
+            };
+            Self {
+                byte,
+                bit_l     : bit.get() as usize,
+                front     : 0,
+                back      : bits.len(),
+                remaining,
+                bytes     : PhantomData,
+                order     : PhantomData
+            }
+        }
+
+        /// Returns the bits of the byte containing the bit at index `offset` which match the searched value, in [`Msb0`] order.
+        ///  Bits before `offset` and bits at or after `self.back` are cleared.
+        ///
+        /// Also returns the index of the first bit of the next byte.
+        #[inline(always)]
+        unsafe fn matches_in_byte(&self, offset : usize) -> (u8, usize,) {
+            let bit     = self.bit_l + offset;
+            let byte    = bit / 8;
+            let mut b   = reorder::<O>(unsafe { *self.byte.add(byte) });
+            if (! $value) { b = ! b; }
+            b &= u8::MAX >> (bit % 8);
+            let end = self.bit_l + self.back;
+            if (end / 8 == byte) {
+                b &= ! (u8::MAX >> (end % 8));
+            }
+            (b, ((byte + 1) * 8) - self.bit_l,)
+        }
+
+    }
+
+    impl<O : BitOrder> Iterator for $ident<'_, O> {
+        type Item = usize;
+
+        #[inline]
+        fn next(&mut self) -> Option<usize> {
+            while (self.front < self.back) {
+                let (matches, next,) = unsafe { self.matches_in_byte(self.front) };
+                if (matches != 0) {
+                    let index = (next + (matches.leading_zeros() as usize)) - 8;
+                    self.front      = index + 1;
+                    self.remaining -= 1;
+                    return Some(index);
+                }
+                self.front = next;
+            }
+            self.front = self.back;
+            None
+        }
+
+        #[inline]
+        fn size_hint(&self) -> (usize, Option<usize>,) {
+            (self.remaining, Some(self.remaining),)
+        }
+
+    }
+
+    impl<O : BitOrder> DoubleEndedIterator for $ident<'_, O> {
+
+        #[inline]
+        fn next_back(&mut self) -> Option<usize> {
+            while (self.front < self.back) {
+                let last  = self.back - 1;
+                let start = last.saturating_sub((self.bit_l + last) % 8).max(self.front);
+                let (matches, next,) = unsafe { self.matches_in_byte(start) };
+                if (matches != 0) {
+                    let index = next - 1 - (matches.trailing_zeros() as usize);
+                    self.back       = index;
+                    self.remaining -= 1;
+                    return Some(index);
+                }
+                self.back = start;
+            }
+            None
+        }
+
+    }
+
+    impl<O : BitOrder> ExactSizeIterator for $ident<'_, O> { }
+
+    impl<O : BitOrder> FusedIterator for $ident<'_, O> { }
+
+} }
+
+iter_indices! {
+    /// An iterator over the indices of the `1` bits of a [`BitSlice`].
+    ///
+    /// Created by [`BitSlice::iter_ones`].
+    IterOnes, true
+}
+
+iter_indices! {
+    /// An iterator over the indices of the `0` bits of a [`BitSlice`].
+    ///
+    /// Created by [`BitSlice::iter_zeros`].
+    IterZeros, false
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Lsb0;
+
+
+    const X : [u8; 4] = [0b01011011, 0b10010110, 0b00000000, 0b11100001];
+
+
+    fn model(range : core::ops::Range<usize>) -> Vec<bool> {
+        range.map(|i| (X[i / 8] << (i % 8)) & 0b10000000 != 0).collect()
+    }
+
+
+    #[test]
+    fn iter_forward_backward() {
+        for start in 0..32 {
+            for end in start..=32 {
+                let bits  = BitSlice::new_range(&X, start..end).unwrap();
+                let model = model(start..end);
+                assert_eq!(bits.iter().len(), model.len());
+                assert_eq!(bits.iter().collect::<Vec<_>>(), model);
+                assert_eq!(bits.iter().rev().collect::<Vec<_>>(), model.iter().copied().rev().collect::<Vec<_>>());
+
+                // Alternate between both ends.
+                let mut iter = bits.iter();
+                let mut got  = (Vec::new(), Vec::new(),);
+                while let Some(b) = iter.next() {
+                    got.0.push(b);
+                    if let Some(b) = iter.next_back() { got.1.push(b); }
+                }
+                got.1.reverse();
+                got.0.extend(got.1);
+                assert_eq!(got.0, model);
+            }
+        }
+    }
+
+
+    #[test]
+    fn iter_ones_zeros() {
+        for start in 0..32 {
+            for end in start..=32 {
+                let bits  = BitSlice::new_range(&X, start..end).unwrap();
+                let model = model(start..end);
+                let ones  = (0..model.len()).filter(|&i| model[i]).collect::<Vec<_>>();
+                let zeros = (0..model.len()).filter(|&i| ! model[i]).collect::<Vec<_>>();
+
+                assert_eq!(bits.iter_ones().len(), ones.len());
+                assert_eq!(bits.iter_ones().collect::<Vec<_>>(), ones);
+                assert_eq!(bits.iter_ones().rev().collect::<Vec<_>>(), ones.iter().copied().rev().collect::<Vec<_>>());
+                assert_eq!(bits.iter_zeros().len(), zeros.len());
+                assert_eq!(bits.iter_zeros().collect::<Vec<_>>(), zeros);
+                assert_eq!(bits.iter_zeros().rev().collect::<Vec<_>>(), zeros.iter().copied().rev().collect::<Vec<_>>());
+
+                let mut iter = bits.iter_ones();
+                if let Some(first) = iter.next() {
+                    assert_eq!(Some(first), ones.first().copied());
+                    assert_eq!(iter.next_back(), if (ones.len() > 1) { ones.last().copied() } else { None });
+                    assert_eq!(iter.len(), ones.len().saturating_sub(2));
+                }
+            }
+        }
+    }
+
+
+    #[test]
+    fn iter_lsb0() {
+        let bits = BitSlice::new_range(&X, 2..12).unwrap().cast_order::<Lsb0>();
+        assert_eq!(bits.iter().collect::<Vec<_>>(), [false, true, true, false, true, false, false, true, true, false]);
+        assert_eq!(bits.iter_ones().collect::<Vec<_>>(), [1, 2, 4, 7, 8]);
+    }
+
+}
@@ -4,6 +4,12 @@
 //! [pointers](primitive@pointer) in Rust are locked to byte-offsets, or every 8 bits.
 //! `bitptr` aims to provide methods to help read and write data at off-byte offsets.
 //!
+//! Bits within a byte are numbered most significant bit first by default. See [`BitOrder`] for other bit orders.
+//!
+//! Pointer arithmetic is `const fn` where `core` allows it. Methods which read or replace the address of a pointer,
+//! such as [`BitPtr::addr`], [`BitPtr::checked_bit_add`] and [`BitPtr::wrapping_bit_offset_from`], are not,
+//! because the addresses of pointers are not known during constant evaluation.
+//!
 //! The primary use for `bitptr` is to pack data when the size of data is not known at compile time.
 //! For types and sizes known at compile time, consider [`bilge`](https://docs.rs/bilge).
 
@@ -12,12 +18,60 @@
 #![cfg_attr(not(test), no_std)]
 
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+
 mod ptr;
-pub use ptr::{ BitPtr, BitPtrMut, SubByte };
+pub use ptr::{ BitPtr, BitPtrMut, NonNullBitPtr, SubByte };
+
+mod atomic;
+#[cfg(target_has_atomic = "ptr")]
+pub use atomic::AtomicBitPtr;
+
+mod order;
+pub use order::{ BitOrder, Msb0, Lsb0 };
+
+mod slice;
+pub use slice::{ BitSlice, BitSliceMut };
+
+pub mod iter;
+
+mod reader;
+pub use reader::{ BitReader, UnexpectedEnd };
+
+mod writer;
+pub use writer::{ BitWriter, BufferFull };
 
+#[cfg(feature = "alloc")]
+mod vec;
+#[cfg(feature = "alloc")]
+pub use vec::{ BitVec, BitBox };
+
+mod int;
+pub use int::{ Unsigned, Signed };
+
+
+mod display;
+pub use display::{ display_bits, DisplayBits };
 
 mod copy;
-pub use copy::copy_nonoverlapping;
+pub use copy::{ copy, copy_nonoverlapping };
+
+mod cmp;
+pub use cmp::{ bits_eq, bits_cmp };
+
+mod bitwise;
+pub use bitwise::{ and_assign, or_assign, xor_assign, andnot_assign, invert };
+
+mod words;
+
+mod simd;
+
+mod count;
+pub use count::{ count_ones, count_zeros, first_one, first_zero, last_one, last_zero, leading_zeros, trailing_zeros };
 
 mod dangling;
 pub use dangling::{ dangling, dangling_mut };
@@ -25,8 +79,14 @@ pub use dangling::{ dangling, dangling_mut };
 mod null;
 pub use null::{ null, null_mut };
 
+mod reverse;
+pub use reverse::{ reverse, rotate_left, rotate_right };
+
+mod shift;
+pub use shift::{ shift_left, shift_right };
+
 mod swap;
 pub use swap::swap_nonoverlapping;
 
 mod fill;
-pub use fill::fill;
+pub use fill::{ fill, fill_volatile, fill_pattern, fill_with_u64 };
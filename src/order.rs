@@ -0,0 +1,66 @@
+use core::fmt::Debug;
+use core::hash::Hash;
+
+
+mod private {
+    pub trait Sealed { }
+}
+
+
+/// The order in which the bits of a byte are numbered.
+///
+/// Implemented by [`Msb0`] and [`Lsb0`].
+pub trait BitOrder : Copy + Eq + Ord + Hash + Default + Debug + Send + Sync + 'static + private::Sealed {
+
+    #[doc(hidden)]
+    const LSB_FIRST : bool;
+
+}
+
+
+/// Bit `0` of a byte is its most significant bit, and bit `7` is its least significant bit.
+///
+/// Multi-bit fields are read and written most significant bit first.
+///
+/// This is the default bit order.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Debug)]
+pub struct Msb0;
+
+impl private::Sealed for Msb0 { }
+
+impl BitOrder for Msb0 {
+    const LSB_FIRST : bool = false;
+}
+
+
+/// Bit `0` of a byte is its least significant bit, and bit `7` is its most significant bit.
+///
+/// Multi-bit fields are read and written least significant bit first.
+///
+/// This is the bit order used by DEFLATE and by many hardware registers.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Debug)]
+pub struct Lsb0;
+
+impl private::Sealed for Lsb0 { }
+
+impl BitOrder for Lsb0 {
+    const LSB_FIRST : bool = true;
+}
+
+
+/// Converts a byte between its in-memory representation under the bit order `O`, and its [`Msb0`] representation.
+///
+/// Bit `n` under `O` becomes bit `n` under [`Msb0`]. This conversion is its own inverse.
+#[inline(always)]
+pub(crate) const fn reorder<O : BitOrder>(byte : u8) -> u8 {
+    if (O::LSB_FIRST) { byte.reverse_bits() } else { byte }
+}
+
+
+/// Converts 8 bytes, loaded as a big-endian [`u64`], between their in-memory representation under the bit order `O`, and their [`Msb0`] representation.
+///
+/// See [`reorder`].
+#[inline(always)]
+pub(crate) const fn reorder_u64<O : BitOrder>(word : u64) -> u64 {
+    if (O::LSB_FIRST) { word.reverse_bits().swap_bytes() } else { word }
+}
@@ -1,53 +1,90 @@
+use crate::{ BitOrder, Msb0 };
+use crate::int::{ self, Unsigned, Signed };
+use crate::order::reorder;
 use core::cmp::{ PartialOrd, Ord, Ordering };
-use core::mem;
+use core::marker::PhantomData;
+use core::fmt;
 
 
 mod subbyte;
 pub use subbyte::SubByte;
 
+mod non_null;
+pub use non_null::NonNullBitPtr;
+
 
 macro_rules! bitptr { (
     $( #[doc = $doc:tt] )*
-    $ident:ident, $byte:ty
+    $ident:ident, $byte:ty, $with_exposed_provenance:path
 ) => {
 
     $( #[doc = $doc] )*
     #[derive(Clone, Copy, PartialEq, Eq, Hash)]
-    pub struct $ident {
-        byte : $byte,
-        bit  : SubByte
+    pub struct $ident<O : BitOrder = Msb0> {
+        byte  : $byte,
+        bit   : SubByte,
+        order : PhantomData<O>
     }
 
-    impl PartialOrd for $ident {
+    impl<O : BitOrder> PartialOrd for $ident<O> {
         #[inline(always)]
         fn partial_cmp(&self, other : &Self) -> Option<Ordering> {
             Some(Ord::cmp(self, other))
         }
     }
 
-    impl Ord for $ident {
+    impl<O : BitOrder> Ord for $ident<O> {
         fn cmp(&self, other : &Self) -> Ordering {
             self.byte.cmp(&other.byte)
                 .then_with(|| self.bit.cmp(&other.bit))
         }
     }
 
+    /// Formats the byte address in hexadecimal, followed by the sub-byte bit offset. For example, `0x7ffd1234.5`.
+    impl<O : BitOrder> fmt::Pointer for $ident<O> {
+        fn fmt(&self, f : &mut fmt::Formatter<'_>) -> fmt::Result {
+            fmt::Pointer::fmt(&self.byte, f)?;
+            write!(f, ".{}", self.bit.get())
+        }
+    }
+
+    /// Formats the same as [`Pointer`](fmt::Pointer).
+    impl<O : BitOrder> fmt::Debug for $ident<O> {
+        fn fmt(&self, f : &mut fmt::Formatter<'_>) -> fmt::Result {
+            fmt::Pointer::fmt(self, f)
+        }
+    }
+
+    /// Formats the byte address in binary, followed by the sub-byte bit offset as 3 binary digits. For example, `1100.101`.
+    impl<O : BitOrder> fmt::Binary for $ident<O> {
+        fn fmt(&self, f : &mut fmt::Formatter<'_>) -> fmt::Result {
+            fmt::Binary::fmt(&self.byte.addr(), f)?;
+            write!(f, ".{:03b}", self.bit.get())
+        }
+    }
+
     impl $ident {
 
         /// Create a new raw bit pointer from a raw byte pointer and a sub-byte bit offset.
+        ///
+        /// The new bit pointer uses [`Msb0`] bit order. See [`cast_order`](Self::cast_order) to use another bit order.
         #[inline(always)]
         pub const fn new(byte : $byte, subbyte_bit : SubByte) -> Self {
-            Self { byte, bit : subbyte_bit }
+            Self { byte, bit : subbyte_bit, order : PhantomData }
         }
 
         /// Create a new raw bit pointer from a raw byte pointer and zero bit offset.
+        ///
+        /// The new bit pointer uses [`Msb0`] bit order. See [`cast_order`](Self::cast_order) to use another bit order.
         #[inline(always)]
         pub const fn new_on_byte(byte : $byte) -> Self {
-            Self { byte, bit : SubByte::MIN }
+            Self { byte, bit : SubByte::MIN, order : PhantomData }
         }
 
         /// Create a new raw bit pointer from a raw byte pointer and a bit offset.
         ///
+        /// The new bit pointer uses [`Msb0`] bit order. See [`cast_order`](Self::cast_order) to use another bit order.
+        ///
         /// # Safety
         /// `new_with_offset` has the same safety concerns as [`(*const _)::offset`](primitive@pointer#method.byte_offset).
         #[inline(always)]
@@ -55,9 +92,20 @@ macro_rules! bitptr { (
             unsafe { Self::new_on_byte(byte).bit_offset(bit_count) }
         }
 
+        /// Create a new raw bit pointer from a byte address and a sub-byte bit offset, picking up any provenance previously exposed by [`expose_provenance`](Self::expose_provenance).
+        ///
+        /// The new bit pointer uses [`Msb0`] bit order. See [`cast_order`](Self::cast_order) to use another bit order.
+        ///
+        /// ---
+        /// Analagous to [`ptr::with_exposed_provenance`](core::ptr::with_exposed_provenance).
+        #[inline]
+        pub fn with_exposed_provenance(addr : usize, subbyte_bit : SubByte) -> Self {
+            Self::new($with_exposed_provenance(addr), subbyte_bit)
+        }
+
     }
 
-    impl $ident {
+    impl<O : BitOrder> $ident<O> {
 
         /// Returns the raw byte pointer, rounded down.
         #[inline]
@@ -71,9 +119,26 @@ macro_rules! bitptr { (
         #[inline]
         pub const fn as_inner(&self) -> ($byte, SubByte,) { (self.byte, self.bit,) }
 
+        /// Changes the bit order of this raw bit pointer, keeping the same byte and sub-byte bit offset.
+        ///
+        /// The pointer will point to a different bit of the same byte, unless the bit orders are the same.
+        ///
+        /// ```rust
+        /// use bitptr::{ BitPtr, Lsb0 };
+        ///
+        /// let x = 0b_00000001_u8;
+        ///
+        /// let xptr = BitPtr::new_on_byte(&x as *const _).cast_order::<Lsb0>();
+        /// assert!(unsafe { xptr.read() });
+        /// ```
+        #[inline(always)]
+        pub const fn cast_order<P : BitOrder>(self) -> $ident<P> {
+            $ident { byte : self.byte, bit : self.bit, order : PhantomData }
+        }
+
     }
 
-    impl $ident {
+    impl<O : BitOrder> $ident<O> {
 
         #[allow(clippy::missing_safety_doc)]
         /// Adds a signed offset in bytes to a bit pointer.
@@ -95,11 +160,61 @@ macro_rules! bitptr { (
         /// Analagous to [`(*const _)::byte_offset`](primitive@pointer#method.byte_offset).
         #[inline]
         pub const unsafe fn bit_offset(mut self, count : isize) -> Self {
-            let bit         = (self.bit.get() as isize) + count;
-            let byte_offset = bit.div_euclid(8);
-            let bit         = bit.rem_euclid(8) as u8;
-            self.byte       = unsafe { self.byte.byte_offset(byte_offset) };
-            self.bit        = unsafe { SubByte::new_unchecked(bit) };
+            let (byte_offset, bit,) = split_bit_offset(self.bit, count);
+            self.byte = unsafe { self.byte.byte_offset(byte_offset) };
+            self.bit  = bit;
+            self
+        }
+
+        #[allow(clippy::missing_safety_doc)]
+        /// Adds an unsigned offset in bytes to a bit pointer.
+        ///
+        /// `count` is in a unit of **bytes**.
+        ///
+        /// Analagous to [`(*const _)::byte_add`](primitive@pointer#method.byte_add).
+        #[inline]
+        pub const unsafe fn byte_add(mut self, count : usize) -> Self {
+            self.byte = unsafe { self.byte.byte_add(count) };
+            self
+        }
+
+        #[allow(clippy::missing_safety_doc)]
+        /// Adds an unsigned offset in bits to a bit pointer.
+        ///
+        /// `count` is in a unit of **bits**.
+        ///
+        /// Analagous to [`(*const _)::byte_add`](primitive@pointer#method.byte_add).
+        #[inline]
+        pub const unsafe fn bit_add(mut self, count : usize) -> Self {
+            let (byte_count, bit,) = split_bit_add(self.bit, count);
+            self.byte = unsafe { self.byte.byte_add(byte_count) };
+            self.bit  = bit;
+            self
+        }
+
+        #[allow(clippy::missing_safety_doc)]
+        /// Subtracts an unsigned offset in bytes from a bit pointer.
+        ///
+        /// `count` is in a unit of **bytes**.
+        ///
+        /// Analagous to [`(*const _)::byte_sub`](primitive@pointer#method.byte_sub).
+        #[inline]
+        pub const unsafe fn byte_sub(mut self, count : usize) -> Self {
+            self.byte = unsafe { self.byte.byte_sub(count) };
+            self
+        }
+
+        #[allow(clippy::missing_safety_doc)]
+        /// Subtracts an unsigned offset in bits from a bit pointer.
+        ///
+        /// `count` is in a unit of **bits**.
+        ///
+        /// Analagous to [`(*const _)::byte_sub`](primitive@pointer#method.byte_sub).
+        #[inline]
+        pub const unsafe fn bit_sub(mut self, count : usize) -> Self {
+            let (byte_count, bit,) = split_bit_sub(self.bit, count);
+            self.byte = unsafe { self.byte.byte_sub(byte_count) };
+            self.bit  = bit;
             self
         }
 
@@ -114,16 +229,419 @@ macro_rules! bitptr { (
             self
         }
 
+        /// Adds a signed offset in bits to a bit pointer using wrapping arithmetic.
+        ///
+        /// `count` is in a unit of **bits**.
+        ///
+        /// Analagous to [`(*const _)::wrapping_byte_offset`](primitive@pointer#method.wrapping_byte_offset).
+        #[inline]
+        pub const fn wrapping_bit_offset(mut self, count : isize) -> Self {
+            let (byte_offset, bit,) = split_bit_offset(self.bit, count);
+            self.byte = self.byte.wrapping_byte_offset(byte_offset);
+            self.bit  = bit;
+            self
+        }
+
+        /// Adds an unsigned offset in bytes to a bit pointer using wrapping arithmetic.
+        ///
+        /// `count` is in a unit of **bytes**.
+        ///
+        /// Analagous to [`(*const _)::wrapping_byte_add`](primitive@pointer#method.wrapping_byte_add).
+        #[inline]
+        pub const fn wrapping_byte_add(mut self, count : usize) -> Self {
+            self.byte = self.byte.wrapping_byte_add(count);
+            self
+        }
+
+        /// Adds an unsigned offset in bits to a bit pointer using wrapping arithmetic.
+        ///
+        /// `count` is in a unit of **bits**.
+        ///
+        /// Analagous to [`(*const _)::wrapping_byte_add`](primitive@pointer#method.wrapping_byte_add).
+        #[inline]
+        pub const fn wrapping_bit_add(mut self, count : usize) -> Self {
+            let (byte_count, bit,) = split_bit_add(self.bit, count);
+            self.byte = self.byte.wrapping_byte_add(byte_count);
+            self.bit  = bit;
+            self
+        }
+
+        /// Subtracts an unsigned offset in bytes from a bit pointer using wrapping arithmetic.
+        ///
+        /// `count` is in a unit of **bytes**.
+        ///
+        /// Analagous to [`(*const _)::wrapping_byte_sub`](primitive@pointer#method.wrapping_byte_sub).
+        #[inline]
+        pub const fn wrapping_byte_sub(mut self, count : usize) -> Self {
+            self.byte = self.byte.wrapping_byte_sub(count);
+            self
+        }
+
+        /// Subtracts an unsigned offset in bits from a bit pointer using wrapping arithmetic.
+        ///
+        /// `count` is in a unit of **bits**.
+        ///
+        /// Analagous to [`(*const _)::wrapping_byte_sub`](primitive@pointer#method.wrapping_byte_sub).
+        #[inline]
+        pub const fn wrapping_bit_sub(mut self, count : usize) -> Self {
+            let (byte_count, bit,) = split_bit_sub(self.bit, count);
+            self.byte = self.byte.wrapping_byte_sub(byte_count);
+            self.bit  = bit;
+            self
+        }
+
+        /// Adds a signed offset in bits to a bit pointer, checking that the address does not wrap around.
+        ///
+        /// `count` is in a unit of **bits**.
+        ///
+        /// This does not check that the result is in the same allocation. See [`bit_offset`](Self::bit_offset) for that.
+        ///
+        /// # Returns
+        /// Returns `None` if the address of the resulting byte would overflow or underflow.
+        #[inline]
+        pub fn checked_bit_offset(self, count : isize) -> Option<Self> {
+            let (byte_offset, _,) = split_bit_offset(self.bit, count);
+            self.byte.addr().checked_add_signed(byte_offset)?;
+            Some(self.wrapping_bit_offset(count))
+        }
+
+        /// Adds an unsigned offset in bits to a bit pointer, checking that the address does not wrap around.
+        ///
+        /// `count` is in a unit of **bits**.
+        ///
+        /// This does not check that the result is in the same allocation. See [`bit_add`](Self::bit_add) for that.
+        ///
+        /// # Returns
+        /// Returns `None` if the address of the resulting byte would overflow.
+        #[inline]
+        pub fn checked_bit_add(self, count : usize) -> Option<Self> {
+            let (byte_count, _,) = split_bit_add(self.bit, count);
+            self.byte.addr().checked_add(byte_count)?;
+            Some(self.wrapping_bit_add(count))
+        }
+
+        /// Subtracts an unsigned offset in bits from a bit pointer, checking that the address does not wrap around.
+        ///
+        /// `count` is in a unit of **bits**.
+        ///
+        /// This does not check that the result is in the same allocation. See [`bit_sub`](Self::bit_sub) for that.
+        ///
+        /// # Returns
+        /// Returns `None` if the address of the resulting byte would underflow.
+        #[inline]
+        pub fn checked_bit_sub(self, count : usize) -> Option<Self> {
+            let (byte_count, _,) = split_bit_sub(self.bit, count);
+            self.byte.addr().checked_sub(byte_count)?;
+            Some(self.wrapping_bit_sub(count))
+        }
+
+        /// Calculates the distance between two bit pointers, in a unit of **bits**.
+        ///
+        /// The result is positive if `self` is after `origin`.
+        ///
+        /// # Safety
+        /// `bit_offset_from` has the same safety concerns as [`(*const _)::offset_from`](primitive@pointer#method.offset_from),
+        ///  on `self.floor_byte()` and `origin.floor_byte()`.
+        ///  Additionally, behaviour is undefined if the distance in bits can not be represented by an [`isize`].
+        ///
+        /// ---
+        /// Analagous to [`(*const _)::offset_from`](primitive@pointer#method.offset_from).
+        ///
+        /// # Examples
+        /// ```rust
+        /// use bitptr::BitPtr;
+        ///
+        /// let x = [0u8; 4];
+        ///
+        /// let a = unsafe { BitPtr::new_with_offset(x.as_ptr(), 5) };
+        /// let b = unsafe { BitPtr::new_with_offset(x.as_ptr(), 19) };
+        /// assert_eq!(unsafe { b.bit_offset_from(a) }, 14);
+        /// assert_eq!(unsafe { a.bit_offset_from(b) }, -14);
+        /// ```
+        #[inline]
+        pub const unsafe fn bit_offset_from(self, origin : Self) -> isize {
+            let bytes = unsafe { self.byte.offset_from(origin.byte) };
+            (bytes * 8) + ((self.bit.get() as isize) - (origin.bit.get() as isize))
+        }
+
+        /// Calculates the distance between two bit pointers, in a unit of **bits**, where `self` is known to be at or after `origin`.
+        ///
+        /// # Safety
+        /// Behaviour is undefined if `self` is before `origin`.
+        ///  Otherwise, `bit_offset_from_unsigned` has the same safety concerns as [`bit_offset_from`](Self::bit_offset_from).
+        ///
+        /// ---
+        /// Analagous to [`(*const _)::offset_from_unsigned`](primitive@pointer#method.offset_from_unsigned).
+        #[inline]
+        pub const unsafe fn bit_offset_from_unsigned(self, origin : Self) -> usize {
+            let bytes = unsafe { self.byte.offset_from_unsigned(origin.byte) };
+            ((bytes * 8) + (self.bit.get() as usize)) - (origin.bit.get() as usize)
+        }
+
+        /// Calculates the distance between two bit pointers, in a unit of **bits**, using wrapping arithmetic.
+        ///
+        /// The pointers do not need to be in the same allocation. The result wraps around if it can not be represented by an [`isize`].
+        #[inline]
+        pub fn wrapping_bit_offset_from(self, origin : Self) -> isize {
+            self.wrapping_bit_offset_from_unsigned(origin) as isize
+        }
+
+        /// Calculates the distance between two bit pointers, in a unit of **bits**, using wrapping arithmetic.
+        ///
+        /// The pointers do not need to be in the same allocation. The result wraps around if `self` is before `origin`.
+        #[inline]
+        pub fn wrapping_bit_offset_from_unsigned(self, origin : Self) -> usize {
+            (self.byte.addr().wrapping_sub(origin.byte.addr()))
+                .wrapping_mul(8)
+                .wrapping_add(self.bit.get() as usize)
+                .wrapping_sub(origin.bit.get() as usize)
+        }
+
+        /// Calculates the distance between two bit pointers, in a unit of **bits**.
+        ///
+        /// The pointers do not need to be in the same allocation.
+        ///
+        /// # Returns
+        /// Returns `None` if the distance in bits can not be represented by an [`isize`].
+        #[inline]
+        pub fn checked_bit_offset_from(self, origin : Self) -> Option<isize> {
+            if (self >= origin) {
+                self.checked_bit_offset_from_unsigned(origin).and_then(|bits| isize::try_from(bits).ok())
+            } else {
+                origin.checked_bit_offset_from_unsigned(self).and_then(|bits| 0isize.checked_sub_unsigned(bits))
+            }
+        }
+
+        /// Calculates the distance between two bit pointers, in a unit of **bits**, where `self` is expected to be at or after `origin`.
+        ///
+        /// The pointers do not need to be in the same allocation.
+        ///
+        /// # Returns
+        /// Returns `None` if `self` is before `origin`, or if the distance in bits can not be represented by a [`usize`].
+        #[inline]
+        pub fn checked_bit_offset_from_unsigned(self, origin : Self) -> Option<usize> {
+            self.byte.addr().checked_sub(origin.byte.addr())?
+                .checked_mul(8)?
+                .checked_add(self.bit.get() as usize)?
+                .checked_sub(origin.bit.get() as usize)
+        }
+
     }
 
-    impl $ident {
+    impl<O : BitOrder> $ident<O> {
+
+        /// Returns the address of the byte that is pointed to, without exposing its provenance.
+        ///
+        /// ---
+        /// Analagous to [`(*const _)::addr`](primitive@pointer#method.addr).
+        #[inline]
+        pub fn addr(self) -> usize { self.byte.addr() }
+
+        /// Creates a new bit pointer with the given byte address, keeping the provenance of `self` and the sub-byte bit offset.
+        ///
+        /// ---
+        /// Analagous to [`(*const _)::with_addr`](primitive@pointer#method.with_addr).
+        #[inline]
+        pub fn with_addr(mut self, addr : usize) -> Self {
+            self.byte = self.byte.with_addr(addr);
+            self
+        }
+
+        /// Creates a new bit pointer by mapping the byte address of `self`, keeping the provenance of `self` and the sub-byte bit offset.
+        ///
+        /// ---
+        /// Analagous to [`(*const _)::map_addr`](primitive@pointer#method.map_addr).
+        #[inline]
+        pub fn map_addr(self, f : impl FnOnce(usize) -> usize) -> Self {
+            self.with_addr(f(self.addr()))
+        }
+
+        /// Returns the address of the bit that is pointed to, in a unit of **bits**, without exposing its provenance.
+        ///
+        /// The bit address is the byte address multiplied by `8`, plus the sub-byte bit offset.
+        ///
+        /// # Panics
+        /// Panics if the bit address can not be represented by a [`usize`]. See [`checked_bit_addr`](Self::checked_bit_addr).
+        #[inline]
+        pub fn bit_addr(self) -> usize {
+            self.checked_bit_addr().expect("bit address overflow")
+        }
+
+        /// Returns the address of the bit that is pointed to, in a unit of **bits**, without exposing its provenance.
+        ///
+        /// # Returns
+        /// Returns `None` if the bit address can not be represented by a [`usize`].
+        #[inline]
+        pub fn checked_bit_addr(self) -> Option<usize> {
+            self.addr().checked_mul(8)?.checked_add(self.bit.get() as usize)
+        }
+
+        /// Creates a new bit pointer with the given bit address, keeping the provenance of `self`.
+        #[inline]
+        pub fn with_bit_addr(mut self, bit_addr : usize) -> Self {
+            self.byte = self.byte.with_addr(bit_addr / 8);
+            self.bit  = unsafe { SubByte::new_unchecked((bit_addr % 8) as u8) };
+            self
+        }
+
+        /// Creates a new bit pointer by mapping the bit address of `self`, keeping the provenance of `self`.
+        ///
+        /// # Panics
+        /// Panics if the bit address of `self` can not be represented by a [`usize`].
+        #[inline]
+        pub fn map_bit_addr(self, f : impl FnOnce(usize) -> usize) -> Self {
+            self.with_bit_addr(f(self.bit_addr()))
+        }
+
+        /// Exposes the provenance of the byte that is pointed to, and returns its byte address and the sub-byte bit offset.
+        ///
+        /// The bit pointer can be recreated with [`with_exposed_provenance`](Self::with_exposed_provenance).
+        ///  Unlike [`bit_addr`](Self::bit_addr), this works for every address.
+        ///
+        /// ---
+        /// Analagous to [`(*const _)::expose_provenance`](primitive@pointer#method.expose_provenance).
+        #[inline]
+        pub fn expose_provenance(self) -> (usize, SubByte,) {
+            (self.byte.expose_provenance(), self.bit,)
+        }
+
+        /// Returns the bits of the byte address that are set in `mask`.
+        ///
+        /// Tags are stored in the byte address, so they do not affect the sub-byte bit offset.
+        ///
+        /// # Examples
+        /// ```rust
+        /// use bitptr::BitPtr;
+        ///
+        /// let x = [0u32; 2];
+        ///
+        /// // `u32`s are aligned, so the lowest bits of their address are free to hold a tag.
+        /// let xptr   = unsafe { BitPtr::new_with_offset(x.as_ptr() as *const u8, 5) };
+        /// let tagged = xptr.with_tag(0b11, 0b10);
+        /// assert_eq!(tagged.tag(0b11), 0b10);
+        /// assert!(tagged.untagged(0b11) == xptr);
+        /// ```
+        #[inline]
+        pub fn tag(self, mask : usize) -> usize {
+            self.addr() & mask
+        }
+
+        /// Creates a new bit pointer with the bits of the byte address that are set in `mask` replaced by those in `tag`, keeping the provenance of `self`.
+        #[inline]
+        pub fn with_tag(self, mask : usize, tag : usize) -> Self {
+            self.map_addr(|addr| (addr & (! mask)) | (tag & mask))
+        }
+
+        /// Creates a new bit pointer with the bits of the byte address that are set in `mask` cleared, keeping the provenance of `self`.
+        #[inline]
+        pub fn untagged(self, mask : usize) -> Self {
+            self.map_addr(|addr| addr & (! mask))
+        }
+
+    }
+
+    impl<O : BitOrder> $ident<O> {
 
         /// Reads the bit that is pointed to.
         ///
         /// # Safety
         /// Behaviour is undefined if `self.floor_byte()` is not [valid](core::ptr#safety) for reads.
         pub const unsafe fn read(self) -> bool {
-            (((unsafe { *self.byte }) << self.bit.get()) & 0b10000000) != 0
+            ((reorder::<O>(unsafe { *self.byte }) << self.bit.get()) & 0b10000000) != 0
+        }
+
+        /// Reads the `width`-bit unsigned integer that is pointed to.
+        ///
+        /// With [`Msb0`](crate::Msb0) bit order, the bit that is pointed to becomes the most significant bit of the value, and the last bit of the field becomes the least significant bit.
+        ///  With [`Lsb0`](crate::Lsb0) bit order, the bit that is pointed to becomes the least significant bit instead.
+        ///  This does not depend on the endianness of the target.
+        ///
+        /// # Panics
+        /// Panics if `width` is greater than `T::BITS`.
+        ///
+        /// # Safety
+        /// Behaviour is undefined if `self.floor_byte()` is not [valid](core::ptr#safety) for reads of `((self.subbyte_bit().get() as usize) + width).div_ceil(8)` bytes.
+        ///
+        /// # Examples
+        /// ```rust
+        /// use bitptr::BitPtr;
+        ///
+        /// let x = [0b_01011011_u8, 0b_10010110_u8];
+        /// //              ^^^^^^^^^^^^^ This is the region that is read.
+        ///
+        /// let xptr = unsafe { BitPtr::new_with_offset(x.as_ptr(), 3) };
+        /// assert_eq!(unsafe { xptr.read_bits::<u16>(10) }, 0b_1101110010_u16);
+        /// ```
+        pub unsafe fn read_bits<T : Unsigned>(self, width : u32) -> T {
+            assert!(width <= T::BITS, "width is greater than the size of the integer type");
+            T::from_u128(unsafe { int::read_u128::<O>(self.byte as *const u8, self.bit, width) })
+        }
+
+        /// Reads the `width`-bit two's complement signed integer that is pointed to, sign-extending it.
+        ///
+        /// With [`Msb0`](crate::Msb0) bit order, the bit that is pointed to is the sign bit, and the last bit of the field becomes the least significant bit.
+        ///  With [`Lsb0`](crate::Lsb0) bit order, the bit that is pointed to becomes the least significant bit, and the last bit of the field is the sign bit.
+        ///  This does not depend on the endianness of the target.
+        ///
+        /// # Panics
+        /// Panics if `width` is greater than `T::BITS`.
+        ///
+        /// # Safety
+        /// Behaviour is undefined if `self.floor_byte()` is not [valid](core::ptr#safety) for reads of `((self.subbyte_bit().get() as usize) + width).div_ceil(8)` bytes.
+        ///
+        /// # Examples
+        /// ```rust
+        /// use bitptr::BitPtr;
+        ///
+        /// let x = [0b_01011011_u8, 0b_10010110_u8];
+        /// //              ^^^^^^^^^^^ This is the region that is read.
+        ///
+        /// let xptr = unsafe { BitPtr::new_with_offset(x.as_ptr(), 3) };
+        /// assert_eq!(unsafe { xptr.read_bits_signed::<i16>(11) }, -0b_00100011011_i16);
+        /// ```
+        pub unsafe fn read_bits_signed<T : Signed>(self, width : u32) -> T {
+            assert!(width <= T::BITS, "width is greater than the size of the integer type");
+            T::from_i128(int::sign_extend(unsafe { int::read_u128::<O>(self.byte as *const u8, self.bit, width) }, width))
+        }
+
+        /// Reads the bit that is pointed to, using a volatile read of its byte.
+        ///
+        /// # Safety
+        /// Behaviour is undefined if `self.floor_byte()` is not [valid](core::ptr#safety) for reads.
+        ///
+        /// ---
+        /// Analagous to [`(*const _)::read_volatile`](primitive@pointer#method.read_volatile).
+        #[inline]
+        pub unsafe fn read_volatile(self) -> bool {
+            ((reorder::<O>(unsafe { self.byte.read_volatile() }) << self.bit.get()) & 0b10000000) != 0
+        }
+
+        /// Reads the `width`-bit unsigned integer that is pointed to, using volatile reads.
+        ///
+        /// Each byte of the field is read exactly once. See [`read_bits`](Self::read_bits).
+        ///
+        /// # Panics
+        /// Panics if `width` is greater than `T::BITS`.
+        ///
+        /// # Safety
+        /// Behaviour is undefined if `self.floor_byte()` is not [valid](core::ptr#safety) for reads of `((self.subbyte_bit().get() as usize) + width).div_ceil(8)` bytes.
+        pub unsafe fn read_bits_volatile<T : Unsigned>(self, width : u32) -> T {
+            assert!(width <= T::BITS, "width is greater than the size of the integer type");
+            T::from_u128(unsafe { int::read_u128_volatile::<O>(self.byte as *const u8, self.bit, width) })
+        }
+
+        /// Reads the `width`-bit two's complement signed integer that is pointed to, sign-extending it, using volatile reads.
+        ///
+        /// Each byte of the field is read exactly once. See [`read_bits_signed`](Self::read_bits_signed).
+        ///
+        /// # Panics
+        /// Panics if `width` is greater than `T::BITS`.
+        ///
+        /// # Safety
+        /// Behaviour is undefined if `self.floor_byte()` is not [valid](core::ptr#safety) for reads of `((self.subbyte_bit().get() as usize) + width).div_ceil(8)` bytes.
+        pub unsafe fn read_bits_signed_volatile<T : Signed>(self, width : u32) -> T {
+            assert!(width <= T::BITS, "width is greater than the size of the integer type");
+            T::from_i128(int::sign_extend(unsafe { int::read_u128_volatile::<O>(self.byte as *const u8, self.bit, width) }, width))
         }
 
     }
@@ -132,19 +650,44 @@ macro_rules! bitptr { (
 } }
 
 
+/// Splits a signed offset in bits from `bit` into a signed offset in bytes and the resulting sub-byte bit offset.
+///
+/// Unlike `bit + count`, this can not overflow.
+#[inline(always)]
+const fn split_bit_offset(bit : SubByte, count : isize) -> (isize, SubByte,) {
+    let bit = (bit.get() as isize) + count.rem_euclid(8);
+    (count.div_euclid(8) + (bit / 8), unsafe { SubByte::new_unchecked((bit % 8) as u8) },)
+}
+
+/// Splits an unsigned offset in bits from `bit` into an unsigned offset in bytes and the resulting sub-byte bit offset.
+#[inline(always)]
+const fn split_bit_add(bit : SubByte, count : usize) -> (usize, SubByte,) {
+    let bit = (bit.get() as usize) + (count % 8);
+    ((count / 8) + (bit / 8), unsafe { SubByte::new_unchecked((bit % 8) as u8) },)
+}
+
+/// Splits an unsigned offset in bits back from `bit` into an unsigned offset in bytes back and the resulting sub-byte bit offset.
+#[inline(always)]
+const fn split_bit_sub(bit : SubByte, count : usize) -> (usize, SubByte,) {
+    let bit    = bit.get() as usize;
+    let borrow = (count % 8) > bit;
+    ((count / 8) + (borrow as usize), unsafe { SubByte::new_unchecked(((bit + 8 - (count % 8)) % 8) as u8) },)
+}
+
+
 bitptr! {
     /// A pointer to a bit in memory.
     ///
     /// Analagous to [`*const T`](core::ptr).
-    BitPtr, *const u8
+    BitPtr, *const u8, core::ptr::with_exposed_provenance
 }
 
-impl BitPtr {
+impl<O : BitOrder> BitPtr<O> {
 
     /// Convert to a [`BitPtrMut`] with the same byte and bit offset.
     #[inline(always)]
-    pub fn as_mut(self) -> BitPtrMut {
-        unsafe { mem::transmute(self) }
+    pub const fn as_mut(self) -> BitPtrMut<O> {
+        BitPtrMut { byte : self.byte.cast_mut(), bit : self.bit, order : PhantomData }
     }
 
 }
@@ -154,15 +697,15 @@ bitptr! {
     /// A mutable pointer to a bit in memory.
     ///
     /// Analagous to [`*mut T`](core::ptr).
-    BitPtrMut, *mut u8
+    BitPtrMut, *mut u8, core::ptr::with_exposed_provenance_mut
 }
 
-impl BitPtrMut {
+impl<O : BitOrder> BitPtrMut<O> {
 
     /// Convert to a [`BitPtr`] with the same byte and bit offset.
     #[inline(always)]
-    pub fn as_const(self) -> BitPtr {
-        unsafe { mem::transmute(self) }
+    pub const fn as_const(self) -> BitPtr<O> {
+        BitPtr { byte : self.byte.cast_const(), bit : self.bit, order : PhantomData }
     }
 
     /// Sets the bit that is pointed to.
@@ -170,7 +713,7 @@ impl BitPtrMut {
     /// # Safety
     /// Behaviour is undefined if `self.floor_byte()` is not [valid](core::ptr#safety) for writes.
     pub const unsafe fn write(self, bit : bool) {
-        let mask = ((u8::MAX << self.bit.get()) & 0b10000000) >> self.bit.get();
+        let mask = reorder::<O>(((u8::MAX << self.bit.get()) & 0b10000000) >> self.bit.get());
         if (bit) {
             unsafe { *self.byte |= mask; }
         } else {
@@ -178,6 +721,146 @@ impl BitPtrMut {
         }
     }
 
+    /// Writes the lowest `width` bits of `value` to the bit field that is pointed to.
+    ///
+    /// With [`Msb0`](crate::Msb0) bit order, the most significant of the written bits is written to the bit that is pointed to, and the least significant to the last bit of the field.
+    ///  With [`Lsb0`](crate::Lsb0) bit order, the least significant of the written bits is written to the bit that is pointed to instead.
+    ///  This does not depend on the endianness of the target. Bits of `value` above `width` are ignored.
+    ///
+    /// # Panics
+    /// Panics if `width` is greater than `T::BITS`.
+    ///
+    /// # Safety
+    /// Behaviour is undefined if `self.floor_byte()` is not [valid](core::ptr#safety) for reads and writes of `((self.subbyte_bit().get() as usize) + width).div_ceil(8)` bytes.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use bitptr::BitPtrMut;
+    ///
+    /// let mut x = [0b_11111111_u8, 0b_11111111_u8];
+    ///
+    /// let xptr = unsafe { BitPtrMut::new_with_offset(x.as_mut_ptr(), 3) };
+    /// unsafe { xptr.write_bits::<u16>(0b_0010001101_u16, 10); }
+    /// assert_eq!(x, [0b_11100100_u8, 0b_01101111_u8]);
+    /// ```
+    pub unsafe fn write_bits<T : Unsigned>(self, value : T, width : u32) {
+        assert!(width <= T::BITS, "width is greater than the size of the integer type");
+        unsafe { int::write_u128::<O>(self.byte, self.bit, value.into_u128(), width); }
+    }
+
+    /// Writes the lowest `width` bits of the two's complement representation of `value` to the bit field that is pointed to.
+    ///
+    /// With [`Msb0`](crate::Msb0) bit order, the sign bit of the field is written to the bit that is pointed to, and the least significant bit to the last bit of the field.
+    ///  With [`Lsb0`](crate::Lsb0) bit order, the least significant bit is written to the bit that is pointed to, and the sign bit to the last bit of the field.
+    ///  This does not depend on the endianness of the target.
+    ///
+    /// `value` is truncated if it does not fit in `width` bits. [`checked_write_bits_signed`](Self::checked_write_bits_signed) can be used to range-check it instead.
+    ///
+    /// # Panics
+    /// Panics if `width` is greater than `T::BITS`.
+    ///
+    /// # Safety
+    /// Behaviour is undefined if `self.floor_byte()` is not [valid](core::ptr#safety) for reads and writes of `((self.subbyte_bit().get() as usize) + width).div_ceil(8)` bytes.
+    pub unsafe fn write_bits_signed<T : Signed>(self, value : T, width : u32) {
+        assert!(width <= T::BITS, "width is greater than the size of the integer type");
+        unsafe { int::write_u128::<O>(self.byte, self.bit, value.into_i128() as u128, width); }
+    }
+
+    /// Writes `value` as a `width`-bit two's complement integer to the bit field that is pointed to, if it fits.
+    ///
+    /// See [`write_bits_signed`](Self::write_bits_signed).
+    ///
+    /// # Returns
+    /// Returns `false` without writing anything if `value` can not be represented in `width` bits.
+    ///
+    /// # Panics
+    /// Panics if `width` is greater than `T::BITS`.
+    ///
+    /// # Safety
+    /// Behaviour is undefined if `self.floor_byte()` is not [valid](core::ptr#safety) for reads and writes of `((self.subbyte_bit().get() as usize) + width).div_ceil(8)` bytes.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use bitptr::BitPtrMut;
+    ///
+    /// let mut x = [0u8; 2];
+    /// let xptr = BitPtrMut::new_on_byte(x.as_mut_ptr());
+    ///
+    /// assert!(unsafe { xptr.checked_write_bits_signed::<i16>(-1024, 11) });
+    /// assert!(! unsafe { xptr.checked_write_bits_signed::<i16>(1024, 11) });
+    /// assert_eq!(x, [0b_10000000_u8, 0b_00000000_u8]);
+    /// ```
+    #[must_use]
+    pub unsafe fn checked_write_bits_signed<T : Signed>(self, value : T, width : u32) -> bool {
+        assert!(width <= T::BITS, "width is greater than the size of the integer type");
+        let value = value.into_i128();
+        if (! int::fits_signed(value, width)) { return false; }
+        unsafe { int::write_u128::<O>(self.byte, self.bit, value as u128, width); }
+        true
+    }
+
+    /// Sets the bit that is pointed to, using a volatile read and a volatile write of its byte.
+    ///
+    /// The read and the write are not atomic. See [`atomic_set`](Self::atomic_set) for that.
+    ///
+    /// # Safety
+    /// Behaviour is undefined if `self.floor_byte()` is not [valid](core::ptr#safety) for reads and writes.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use bitptr::BitPtrMut;
+    ///
+    /// #[repr(C)]
+    /// struct Registers { control : u8, status : u8 }
+    ///
+    /// let mut regs = Registers { control : 0b_00000000_u8, status : 0b_00100000_u8 };
+    ///
+    /// let enable = unsafe { BitPtrMut::new_with_offset(&raw mut regs.control, 7) };
+    /// let ready  = unsafe { BitPtrMut::new_with_offset(&raw mut regs.status, 2) };
+    /// unsafe { enable.write_volatile(true); }
+    /// assert!(unsafe { ready.as_const().read_volatile() });
+    /// assert_eq!(regs.control, 0b_00000001_u8);
+    /// ```
+    ///
+    /// ---
+    /// Analagous to [`(*mut _)::write_volatile`](primitive@pointer#method.write_volatile).
+    #[inline]
+    pub unsafe fn write_volatile(self, bit : bool) {
+        let mask = reorder::<O>(0b10000000 >> self.bit.get());
+        let byte = unsafe { self.byte.read_volatile() };
+        unsafe { self.byte.write_volatile(if (bit) { byte | mask } else { byte & ! mask }); }
+    }
+
+    /// Writes the lowest `width` bits of `value` to the bit field that is pointed to, using volatile reads and writes.
+    ///
+    /// Only the bytes which are partially covered by the field are read. Each byte of the field is read at most once, and written exactly once.
+    ///  See [`write_bits`](Self::write_bits).
+    ///
+    /// # Panics
+    /// Panics if `width` is greater than `T::BITS`.
+    ///
+    /// # Safety
+    /// Behaviour is undefined if `self.floor_byte()` is not [valid](core::ptr#safety) for reads and writes of `((self.subbyte_bit().get() as usize) + width).div_ceil(8)` bytes.
+    pub unsafe fn write_bits_volatile<T : Unsigned>(self, value : T, width : u32) {
+        assert!(width <= T::BITS, "width is greater than the size of the integer type");
+        unsafe { int::write_u128_volatile::<O>(self.byte, self.bit, value.into_u128(), width); }
+    }
+
+    /// Writes the lowest `width` bits of the two's complement representation of `value` to the bit field that is pointed to, using volatile reads and writes.
+    ///
+    /// Only the bytes which are partially covered by the field are read. Each byte of the field is read at most once, and written exactly once.
+    ///  See [`write_bits_signed`](Self::write_bits_signed).
+    ///
+    /// # Panics
+    /// Panics if `width` is greater than `T::BITS`.
+    ///
+    /// # Safety
+    /// Behaviour is undefined if `self.floor_byte()` is not [valid](core::ptr#safety) for reads and writes of `((self.subbyte_bit().get() as usize) + width).div_ceil(8)` bytes.
+    pub unsafe fn write_bits_signed_volatile<T : Signed>(self, value : T, width : u32) {
+        assert!(width <= T::BITS, "width is greater than the size of the integer type");
+        unsafe { int::write_u128_volatile::<O>(self.byte, self.bit, value.into_i128() as u128, width); }
+    }
+
 
 }
 
@@ -185,6 +868,7 @@ impl BitPtrMut {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::Lsb0;
 
     #[test]
     fn bitptr_new_offset() {
@@ -249,4 +933,296 @@ mod tests {
 
     }
 
+
+    #[test]
+    fn bitptr_read_write_bits() {
+        let x = [0b01011011u8, 0b10010110, 0b11100001, 0b00111010];
+
+        let xptr = unsafe { BitPtr::new_with_offset(x.as_ptr(), 6) };
+        assert_eq!(unsafe { xptr.read_bits::<u8>(0) }, 0);
+        assert_eq!(unsafe { xptr.read_bits::<u8>(2) }, 0b11);
+        assert_eq!(unsafe { xptr.read_bits::<u16>(13) }, 0b1110010110111);
+        assert_eq!(unsafe { xptr.read_bits::<u32>(26) }, 0b11100101101110000100111010);
+
+        for bit in 0..8 {
+            for width in 0..=(32 - bit) {
+                let mut y = [0u8; 4];
+                let yptr = unsafe { BitPtrMut::new_with_offset(y.as_mut_ptr(), bit as isize) };
+                let value = unsafe { BitPtr::new_with_offset(x.as_ptr(), bit as isize).read_bits::<u32>(width) };
+                // Bits above `width` must be ignored.
+                unsafe { yptr.write_bits::<u32>(value | u32::MAX.checked_shl(width).unwrap_or(0), width); }
+                assert_eq!(unsafe { yptr.as_const().read_bits::<u32>(width) }, value);
+                for i in 0..32 {
+                    let expected = (i >= bit && i < bit + width) && ((x[(i / 8) as usize] << (i % 8)) & 0b10000000) != 0;
+                    assert_eq!(unsafe { BitPtr::new_with_offset(y.as_ptr(), i as isize).read() }, expected);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn bitptr_read_write_bits_u128() {
+        let x = [0b10101010u8; 17];
+        let mut y = [0u8; 17];
+
+        let xptr = unsafe { BitPtr::new_with_offset(x.as_ptr(), 7) };
+        let yptr = unsafe { BitPtrMut::new_with_offset(y.as_mut_ptr(), 1) };
+        let value = unsafe { xptr.read_bits::<u128>(128) };
+        assert_eq!(value, 0x5555_5555_5555_5555_5555_5555_5555_5555);
+        unsafe { yptr.write_bits(value, 128); }
+        assert_eq!(y[0], 0b00101010);
+        assert_eq!(y[16], 0b10000000);
+        assert!(y[1..16].iter().all(|&b| b == 0b10101010));
+    }
+
+    #[test]
+    fn bitptr_read_write_bits_word_boundary() {
+        // Widths on both sides of the single word path, ending exactly at the end of the buffer.
+        fn check<O : BitOrder>() {
+            let x = [0b01011011u8, 0b10010110, 0b11100001, 0b00111010, 0b10100101, 0b01100110, 0b11000011, 0b00011110, 0b11110000,
+                     0b01011011u8, 0b10010110, 0b11100001, 0b00111010, 0b10100101, 0b01100110, 0b11000011, 0b00011110];
+            for bit in 0..8usize {
+                for width in 0..=(128.min(x.len() * 8 - bit)) {
+                    let byte_count = (bit + width).div_ceil(8);
+                    let xptr = unsafe { BitPtr::new_on_byte(x[(x.len() - byte_count)..].as_ptr()).cast_order::<O>().bit_add(bit) };
+                    let mut expected = 0u128;
+                    for i in 0..width {
+                        let b = unsafe { xptr.bit_add(i).read() } as u128;
+                        expected = if (O::LSB_FIRST) { expected | (b << i) } else { (expected << 1) | b };
+                    }
+                    assert_eq!(unsafe { xptr.read_bits::<u128>(width as u32) }, expected);
+
+                    let mut y = [0u8; 17];
+                    let yptr = unsafe { BitPtrMut::new_on_byte(y[(17 - byte_count)..].as_mut_ptr()).cast_order::<O>().bit_add(bit) };
+                    unsafe { yptr.write_bits::<u128>(expected, width as u32); }
+                    for i in 0..(y.len() * 8) {
+                        let zptr = unsafe { BitPtr::new_on_byte(y.as_ptr()).cast_order::<O>().bit_add(i) };
+                        let j    = i.wrapping_sub((y.len() - byte_count) * 8 + bit);
+                        let want = (j < width) && unsafe { xptr.bit_add(j).read() };
+                        assert_eq!(unsafe { zptr.read() }, want);
+                    }
+                }
+            }
+        }
+        check::<Msb0>();
+        check::<Lsb0>();
+    }
+
+    #[test]
+    #[should_panic]
+    fn bitptr_read_bits_too_wide() {
+        let x = [0u8; 2];
+        let _ = unsafe { BitPtr::new_on_byte(x.as_ptr()).read_bits::<u8>(9) };
+    }
+
+    #[test]
+    fn bitptr_read_write_bits_signed() {
+        let mut x = [0u8; 3];
+        let xptr = unsafe { BitPtrMut::new_with_offset(x.as_mut_ptr(), 5) };
+
+        for width in 1..=16 {
+            let min = -(1i32 << (width - 1));
+            let max = (1i32 << (width - 1)) - 1;
+            for value in [min, min + 1, -1, 0, 1, max - 1, max].into_iter().filter(|v| (min..=max).contains(v)) {
+                assert!(unsafe { xptr.checked_write_bits_signed(value, width) });
+                assert_eq!(unsafe { xptr.as_const().read_bits_signed::<i32>(width) }, value);
+            }
+            assert!(! unsafe { xptr.checked_write_bits_signed(min - 1, width) });
+            assert!(! unsafe { xptr.checked_write_bits_signed(max + 1, width) });
+            assert_eq!(unsafe { xptr.as_const().read_bits_signed::<i32>(width) }, max);
+
+            // Truncation keeps the lowest bits.
+            unsafe { xptr.write_bits_signed(max + 1, width); }
+            assert_eq!(unsafe { xptr.as_const().read_bits_signed::<i32>(width) }, min);
+        }
+        assert_eq!(x[0] & 0b11111000, 0);
+        assert_eq!(x[2] & 0b00000111, 0);
+    }
+
+    #[test]
+    fn bitptr_read_bits_signed_full_width() {
+        let x = i64::MIN.to_be_bytes();
+        assert_eq!(unsafe { BitPtr::new_on_byte(x.as_ptr()).read_bits_signed::<i64>(64) }, i64::MIN);
+        assert_eq!(unsafe { BitPtr::new_on_byte(x.as_ptr()).read_bits_signed::<i64>(0) }, 0);
+    }
+
+    #[test]
+    fn bitptr_lsb0_read_write() {
+        let mut x = 0b01001110u8;
+
+        let xptr = BitPtrMut::new_on_byte(&mut x as *mut _).cast_order::<Lsb0>();
+        assert_eq!(unsafe { xptr.as_const().read() }, false);
+        assert_eq!(unsafe { xptr.bit_offset(1).as_const().read() }, true);
+        assert_eq!(unsafe { xptr.bit_offset(6).as_const().read() }, true);
+        assert_eq!(unsafe { xptr.bit_offset(7).as_const().read() }, false);
+
+        unsafe { xptr.write(true); }
+        assert_eq!(x, 0b01001111u8);
+
+        unsafe { xptr.bit_offset(6).write(false); }
+        assert_eq!(x, 0b00001111u8);
+    }
+
+    #[test]
+    fn bitptr_lsb0_read_write_bits() {
+        let x = 0x123456u32.to_le_bytes();
+
+        // Least significant bit first matches little-endian integer layout.
+        let xptr = BitPtr::new_on_byte(x.as_ptr()).cast_order::<Lsb0>();
+        assert_eq!(unsafe { xptr.read_bits::<u32>(24) }, 0x123456);
+        assert_eq!(unsafe { xptr.bit_offset(4).read_bits::<u16>(12) }, 0x345);
+        assert_eq!(unsafe { xptr.bit_offset(4).read_bits_signed::<i16>(12) }, 0x345);
+        assert_eq!(unsafe { xptr.bit_offset(8).read_bits_signed::<i16>(4) }, 4);
+        assert_eq!(unsafe { xptr.bit_offset(8).read_bits_signed::<i16>(3) }, -4);
+
+        let mut y = [0u8; 4];
+        let yptr = BitPtrMut::new_on_byte(y.as_mut_ptr()).cast_order::<Lsb0>();
+        unsafe { yptr.bit_offset(5).write_bits::<u32>(0xFF_ABCDE, 20); }
+        assert_eq!(u32::from_le_bytes(y), 0xABCDE << 5);
+        unsafe { yptr.bit_offset(5).write_bits_signed::<i32>(-1, 20); }
+        assert_eq!(u32::from_le_bytes(y), 0xFFFFF << 5);
+    }
+
+    #[test]
+    fn bitptr_bit_offset_from() {
+        let x = [0u8; 4];
+        let base = BitPtr::new_on_byte(x.as_ptr());
+
+        for a in 0..32isize {
+            for b in 0..32isize {
+                let aptr = unsafe { base.bit_offset(a) };
+                let bptr = unsafe { base.bit_offset(b) };
+                assert_eq!(unsafe { bptr.bit_offset_from(aptr) }, b - a);
+                assert_eq!(bptr.wrapping_bit_offset_from(aptr), b - a);
+                assert_eq!(bptr.checked_bit_offset_from(aptr), Some(b - a));
+                if (b >= a) {
+                    assert_eq!(unsafe { bptr.bit_offset_from_unsigned(aptr) }, (b - a) as usize);
+                    assert_eq!(bptr.checked_bit_offset_from_unsigned(aptr), Some((b - a) as usize));
+                } else {
+                    assert_eq!(bptr.checked_bit_offset_from_unsigned(aptr), None);
+                    assert_eq!(bptr.wrapping_bit_offset_from_unsigned(aptr), (b - a) as usize);
+                }
+            }
+        }
+
+        let yptr = BitPtrMut::new_on_byte(core::ptr::without_provenance_mut::<u8>(usize::MAX));
+        let zptr = BitPtrMut::new_on_byte(core::ptr::null_mut::<u8>());
+        assert_eq!(yptr.checked_bit_offset_from(zptr), None);
+        assert_eq!(zptr.checked_bit_offset_from(yptr), None);
+        assert_eq!(yptr.checked_bit_offset_from_unsigned(zptr), None);
+        assert_eq!(yptr.wrapping_bit_offset_from(zptr), -8);
+    }
+
+
+    #[test]
+    fn bitptr_bit_add_sub() {
+        let x = [0u8; 4];
+
+        for start in 0..32usize {
+            let base = unsafe { BitPtr::new_on_byte(x.as_ptr()).bit_add(start) };
+            for count in 0..(32 - start) {
+                let expected = unsafe { BitPtr::new_with_offset(x.as_ptr(), (start + count) as isize) };
+                assert!(unsafe { base.bit_add(count) } == expected);
+                assert!(unsafe { base.bit_offset(count as isize) } == expected);
+                assert!(base.wrapping_bit_add(count) == expected);
+                assert!(base.checked_bit_add(count) == Some(expected));
+                assert!(unsafe { expected.bit_sub(count) } == base);
+                assert!(unsafe { expected.bit_offset(- (count as isize)) } == base);
+                assert!(expected.wrapping_bit_sub(count) == base);
+                assert!(expected.checked_bit_sub(count) == Some(base));
+            }
+        }
+    }
+
+
+    #[test]
+    fn bitptr_wrapping_checked_extremes() {
+        let high = BitPtrMut::new(core::ptr::without_provenance_mut::<u8>(usize::MAX), SubByte::MAX);
+        let low  = BitPtrMut::new_on_byte(core::ptr::null_mut::<u8>());
+
+        assert!(high.checked_bit_add(1).is_none());
+        assert!(high.wrapping_bit_add(1) == low);
+        assert!(low.checked_bit_sub(1).is_none());
+        assert!(low.wrapping_bit_sub(1) == high);
+        assert!(low.checked_bit_offset(-1).is_none());
+        assert!(low.wrapping_bit_offset(-1) == high);
+
+        // Offsets near the limits of `isize` do not overflow when combined with the sub-byte bit offset.
+        let mid  = BitPtrMut::new(core::ptr::without_provenance_mut::<u8>(usize::MAX / 2), SubByte::MAX);
+        let next = mid.wrapping_bit_offset(isize::MAX);
+        assert_eq!(next.floor_byte().addr(), (usize::MAX / 2) + (usize::MAX / 16) + 1);
+        assert_eq!(next.subbyte_bit().get(), 6);
+        assert!(mid.checked_bit_offset(isize::MAX) == Some(next));
+        assert!(high.checked_bit_add(usize::MAX).is_none());
+    }
+
+
+    #[test]
+    fn bitptr_bit_addr() {
+        let mut x = [0u8; 4];
+        let xptr = BitPtrMut::new_on_byte(x.as_mut_ptr());
+
+        for offset in 0..32 {
+            let yptr = unsafe { xptr.bit_add(offset) };
+            assert_eq!(yptr.bit_addr(), (x.as_ptr().addr() * 8) + offset);
+            assert!(xptr.with_bit_addr(yptr.bit_addr()) == yptr);
+            assert!(xptr.map_bit_addr(|addr| addr + offset) == yptr);
+            let (addr, bit,) = yptr.expose_provenance();
+            assert!(BitPtrMut::with_exposed_provenance(addr, bit) == yptr);
+        }
+        let yptr = unsafe { xptr.bit_add(13) };
+        assert!(yptr.with_addr(xptr.addr()) == unsafe { xptr.bit_add(5) });
+        unsafe { yptr.map_addr(|addr| addr + 1).write(true); }
+        assert_eq!(x, [0, 0, 0b00000100, 0]);
+
+        let high = BitPtr::new(core::ptr::without_provenance::<u8>(usize::MAX), SubByte::MAX);
+        assert_eq!(high.checked_bit_addr(), None);
+        assert_eq!(high.expose_provenance(), (usize::MAX, SubByte::MAX,));
+        assert!(BitPtr::with_exposed_provenance(usize::MAX, SubByte::MAX) == high);
+    }
+
+
+    #[test]
+    #[should_panic(expected = "bit address overflow")]
+    fn bitptr_bit_addr_overflow() {
+        // The lowest byte address whose bit address overflows, on every target.
+        BitPtr::new_on_byte(core::ptr::without_provenance::<u8>((usize::MAX / 8) + 1)).bit_addr();
+    }
+
+
+    #[test]
+    fn bitptr_volatile_register_block() {
+        // A plain-memory stand-in for a block of memory-mapped registers.
+        #[repr(C)]
+        struct Registers { control : u8, data : [u8; 3], status : u8 }
+        let mut regs = Registers { control : 0b00000000, data : [0b01011011, 0b10010110, 0b11100001], status : 0b10000001 };
+
+        let control = BitPtrMut::new_on_byte(&raw mut regs.control);
+        let bytes   = &raw mut regs.data;
+        let data    = BitPtrMut::new_on_byte(bytes.cast::<u8>());
+        let status  = BitPtrMut::new_on_byte(&raw mut regs.status);
+        unsafe { control.bit_add(7).write_volatile(true); }
+        unsafe { control.bit_add(7).write_volatile(true); }
+        unsafe { control.bit_add(1).write_volatile(false); }
+        assert_eq!(unsafe { status.as_const().read_volatile() }, true);
+        assert_eq!(unsafe { status.bit_add(1).as_const().read_volatile() }, false);
+        for start in 0..24 {
+            for width in 0..=(24 - start) {
+                let field = unsafe { data.bit_add(start) };
+                assert_eq!(unsafe { field.read_bits_volatile::<u32>(width as u32) }, unsafe { field.read_bits::<u32>(width as u32) });
+                assert_eq!(unsafe { field.read_bits_signed_volatile::<i32>(width as u32) }, unsafe { field.read_bits_signed::<i32>(width as u32) });
+                // Accessed through `bytes`, so that `data` and `field` stay valid.
+                let before = unsafe { bytes.read() };
+                unsafe { field.write_bits_volatile::<u32>(0x00_A5C3E1, width as u32); }
+                let after  = unsafe { bytes.read() };
+                unsafe { bytes.write(before); }
+                unsafe { field.write_bits::<u32>(0x00_A5C3E1, width as u32); }
+                assert_eq!(unsafe { bytes.read() }, after);
+                unsafe { field.cast_order::<Lsb0>().write_bits_signed_volatile::<i32>(-3, width as u32); }
+            }
+        }
+        assert_eq!(regs.control, 0b00000001);
+        assert_eq!(regs.status, 0b10000001);
+    }
+
 }
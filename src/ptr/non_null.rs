@@ -0,0 +1,712 @@
+use crate::{ BitPtr, BitPtrMut, BitOrder, Msb0, SubByte, Unsigned, Signed };
+use core::cmp::{ PartialOrd, Ord, Ordering };
+use core::ops::{ Add, AddAssign, Sub, SubAssign };
+use core::marker::PhantomData;
+use core::fmt;
+use core::ptr::NonNull;
+use core::num::NonZero;
+
+
+/// A non-null pointer to a bit in memory.
+///
+/// `Option<NonNullBitPtr>` is the same size as `NonNullBitPtr`.
+///
+/// Analagous to [`NonNull`].
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NonNullBitPtr<O : BitOrder = Msb0> {
+    byte  : NonNull<u8>,
+    bit   : SubByte,
+    order : PhantomData<O>
+}
+
+impl<O : BitOrder> PartialOrd for NonNullBitPtr<O> {
+    #[inline(always)]
+    fn partial_cmp(&self, other : &Self) -> Option<Ordering> {
+        Some(Ord::cmp(self, other))
+    }
+}
+
+impl<O : BitOrder> Ord for NonNullBitPtr<O> {
+    fn cmp(&self, other : &Self) -> Ordering {
+        self.byte.cmp(&other.byte)
+            .then_with(|| self.bit.cmp(&other.bit))
+    }
+}
+
+/// Formats the same as [`BitPtrMut`].
+impl<O : BitOrder> fmt::Pointer for NonNullBitPtr<O> {
+    fn fmt(&self, f : &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Pointer::fmt(&self.as_ptr(), f)
+    }
+}
+
+/// Formats the same as [`BitPtrMut`].
+impl<O : BitOrder> fmt::Debug for NonNullBitPtr<O> {
+    fn fmt(&self, f : &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.as_ptr(), f)
+    }
+}
+
+/// Formats the same as [`BitPtrMut`].
+impl<O : BitOrder> fmt::Binary for NonNullBitPtr<O> {
+    fn fmt(&self, f : &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Binary::fmt(&self.as_ptr(), f)
+    }
+}
+
+impl<O : BitOrder> From<NonNullBitPtr<O>> for BitPtrMut<O> {
+    #[inline(always)]
+    fn from(ptr : NonNullBitPtr<O>) -> Self { ptr.as_ptr() }
+}
+
+impl<O : BitOrder> From<NonNullBitPtr<O>> for BitPtr<O> {
+    #[inline(always)]
+    fn from(ptr : NonNullBitPtr<O>) -> Self { ptr.as_const() }
+}
+
+/// Adds an offset in bits, checking that the address does not wrap around. See [`checked_bit_add`](NonNullBitPtr::checked_bit_add).
+///
+/// # Panics
+/// Panics if the address of the resulting byte would overflow.
+impl<O : BitOrder> Add<usize> for NonNullBitPtr<O> {
+    type Output = Self;
+    #[inline(always)]
+    fn add(self, count : usize) -> Self { self.checked_bit_add(count).expect("bit pointer overflow") }
+}
+
+impl<O : BitOrder> AddAssign<usize> for NonNullBitPtr<O> {
+    #[inline(always)]
+    fn add_assign(&mut self, count : usize) { *self = *self + count; }
+}
+
+/// Subtracts an offset in bits, checking that the address does not wrap around. See [`checked_bit_sub`](NonNullBitPtr::checked_bit_sub).
+///
+/// # Panics
+/// Panics if the address of the resulting byte would underflow, or if the resulting byte pointer is null.
+impl<O : BitOrder> Sub<usize> for NonNullBitPtr<O> {
+    type Output = Self;
+    #[inline(always)]
+    fn sub(self, count : usize) -> Self { self.checked_bit_sub(count).expect("bit pointer underflow") }
+}
+
+impl<O : BitOrder> SubAssign<usize> for NonNullBitPtr<O> {
+    #[inline(always)]
+    fn sub_assign(&mut self, count : usize) { *self = *self - count; }
+}
+
+/// Converts a raw bit pointer, returning it unchanged as the error if it is null. See [`NonNullBitPtr::new`].
+impl<O : BitOrder> TryFrom<BitPtrMut<O>> for NonNullBitPtr<O> {
+    type Error = BitPtrMut<O>;
+    #[inline]
+    fn try_from(ptr : BitPtrMut<O>) -> Result<Self, BitPtrMut<O>> { Self::new(ptr).ok_or(ptr) }
+}
+
+/// Converts a raw bit pointer, returning it unchanged as the error if it is null. See [`NonNullBitPtr::new`].
+impl<O : BitOrder> TryFrom<BitPtr<O>> for NonNullBitPtr<O> {
+    type Error = BitPtr<O>;
+    #[inline]
+    fn try_from(ptr : BitPtr<O>) -> Result<Self, BitPtr<O>> { Self::new(ptr.as_mut()).ok_or(ptr) }
+}
+
+/// Points to the first bit of the byte, in [`Msb0`] bit order. See [`NonNullBitPtr::new_on_byte`].
+impl From<NonNull<u8>> for NonNullBitPtr {
+    #[inline(always)]
+    fn from(byte : NonNull<u8>) -> Self { Self::new_on_byte(byte) }
+}
+
+impl NonNullBitPtr {
+
+    /// Create a new non-null bit pointer from a non-null byte pointer and zero bit offset.
+    ///
+    /// The new bit pointer uses [`Msb0`] bit order. See [`cast_order`](Self::cast_order) to use another bit order.
+    #[inline(always)]
+    pub const fn new_on_byte(byte : NonNull<u8>) -> Self {
+        Self { byte, bit : SubByte::MIN, order : PhantomData }
+    }
+
+    /// Creates a dangling non-null bit pointer.
+    ///
+    /// The new bit pointer uses [`Msb0`] bit order. See [`cast_order`](Self::cast_order) to use another bit order.
+    ///
+    /// ---
+    /// Analagous to [`NonNull::dangling`].
+    #[inline(always)]
+    pub const fn dangling() -> Self {
+        Self { byte : NonNull::dangling(), bit : SubByte::MIN, order : PhantomData }
+    }
+
+}
+
+impl<O : BitOrder> NonNullBitPtr<O> {
+
+    /// Create a new non-null bit pointer from a raw bit pointer.
+    ///
+    /// # Returns
+    /// Returns `None` if `ptr.floor_byte()` is null.
+    ///
+    /// ---
+    /// Analagous to [`NonNull::new`].
+    #[inline]
+    pub const fn new(ptr : BitPtrMut<O>) -> Option<Self> {
+        match (NonNull::new(ptr.floor_byte())) {
+            Some(byte) => Some(Self { byte, bit : ptr.subbyte_bit(), order : PhantomData }),
+            None       => None
+        }
+    }
+
+    /// Create a new non-null bit pointer from a raw bit pointer, without checking if it is null.
+    ///
+    /// # Safety
+    /// Behaviour is undefined if `ptr.floor_byte()` is null.
+    ///
+    /// ---
+    /// Analagous to [`NonNull::new_unchecked`].
+    #[inline(always)]
+    pub const unsafe fn new_unchecked(ptr : BitPtrMut<O>) -> Self {
+        Self { byte : unsafe { NonNull::new_unchecked(ptr.floor_byte()) }, bit : ptr.subbyte_bit(), order : PhantomData }
+    }
+
+    /// Returns the raw mutable bit pointer.
+    ///
+    /// ---
+    /// Analagous to [`NonNull::as_ptr`].
+    #[inline(always)]
+    pub const fn as_ptr(self) -> BitPtrMut<O> {
+        BitPtrMut::new(self.byte.as_ptr(), self.bit).cast_order()
+    }
+
+    /// Returns the raw bit pointer.
+    #[inline(always)]
+    pub const fn as_const(self) -> BitPtr<O> {
+        self.as_ptr().as_const()
+    }
+
+    /// Returns the non-null byte pointer, rounded down.
+    #[inline]
+    pub const fn floor_byte(&self) -> NonNull<u8> { self.byte }
+
+    /// Returns the sub-byte bit offset of this bit pointer.
+    #[inline]
+    pub const fn subbyte_bit(&self) -> SubByte { self.bit }
+
+    /// Returns the non-null byte pointer (rounded down) and sub-byte bit offset of this bit pointer.
+    #[inline]
+    pub const fn as_inner(&self) -> (NonNull<u8>, SubByte,) { (self.byte, self.bit,) }
+
+    /// Changes the bit order of this bit pointer, keeping the same byte and sub-byte bit offset.
+    ///
+    /// See [`BitPtrMut::cast_order`].
+    #[inline(always)]
+    pub const fn cast_order<P : BitOrder>(self) -> NonNullBitPtr<P> {
+        NonNullBitPtr { byte : self.byte, bit : self.bit, order : PhantomData }
+    }
+
+}
+
+impl<O : BitOrder> NonNullBitPtr<O> {
+
+    /// Adds a signed offset in bytes to a bit pointer.
+    ///
+    /// # Safety
+    /// See [`BitPtrMut::byte_offset`].
+    #[inline]
+    pub const unsafe fn byte_offset(self, count : isize) -> Self {
+        unsafe { Self::new_unchecked(self.as_ptr().byte_offset(count)) }
+    }
+
+    /// Adds a signed offset in bits to a bit pointer.
+    ///
+    /// # Safety
+    /// See [`BitPtrMut::bit_offset`].
+    #[inline]
+    pub const unsafe fn bit_offset(self, count : isize) -> Self {
+        unsafe { Self::new_unchecked(self.as_ptr().bit_offset(count)) }
+    }
+
+    /// Adds an unsigned offset in bytes to a bit pointer.
+    ///
+    /// # Safety
+    /// See [`BitPtrMut::byte_add`].
+    #[inline]
+    pub const unsafe fn byte_add(self, count : usize) -> Self {
+        unsafe { Self::new_unchecked(self.as_ptr().byte_add(count)) }
+    }
+
+    /// Adds an unsigned offset in bits to a bit pointer.
+    ///
+    /// # Safety
+    /// See [`BitPtrMut::bit_add`].
+    #[inline]
+    pub const unsafe fn bit_add(self, count : usize) -> Self {
+        unsafe { Self::new_unchecked(self.as_ptr().bit_add(count)) }
+    }
+
+    /// Subtracts an unsigned offset in bytes from a bit pointer.
+    ///
+    /// # Safety
+    /// See [`BitPtrMut::byte_sub`].
+    #[inline]
+    pub const unsafe fn byte_sub(self, count : usize) -> Self {
+        unsafe { Self::new_unchecked(self.as_ptr().byte_sub(count)) }
+    }
+
+    /// Subtracts an unsigned offset in bits from a bit pointer.
+    ///
+    /// # Safety
+    /// See [`BitPtrMut::bit_sub`].
+    #[inline]
+    pub const unsafe fn bit_sub(self, count : usize) -> Self {
+        unsafe { Self::new_unchecked(self.as_ptr().bit_sub(count)) }
+    }
+
+    /// Calculates the distance between two bit pointers, in a unit of **bits**.
+    ///
+    /// # Safety
+    /// See [`BitPtrMut::bit_offset_from`].
+    #[inline]
+    pub const unsafe fn bit_offset_from(self, origin : Self) -> isize {
+        unsafe { self.as_ptr().bit_offset_from(origin.as_ptr()) }
+    }
+
+    /// Calculates the distance between two bit pointers, in a unit of **bits**, where `self` is known to be at or after `origin`.
+    ///
+    /// # Safety
+    /// See [`BitPtrMut::bit_offset_from_unsigned`].
+    #[inline]
+    pub const unsafe fn bit_offset_from_unsigned(self, origin : Self) -> usize {
+        unsafe { self.as_ptr().bit_offset_from_unsigned(origin.as_ptr()) }
+    }
+
+    /// Adds a signed offset in bytes to a bit pointer using wrapping arithmetic.
+    ///
+    /// See [`BitPtrMut::wrapping_byte_offset`].
+    ///
+    /// # Returns
+    /// Returns `None` if the resulting byte pointer is null.
+    #[inline]
+    pub const fn wrapping_byte_offset(self, count : isize) -> Option<Self> {
+        Self::new(self.as_ptr().wrapping_byte_offset(count))
+    }
+
+    /// Adds a signed offset in bits to a bit pointer using wrapping arithmetic.
+    ///
+    /// See [`BitPtrMut::wrapping_bit_offset`].
+    ///
+    /// # Returns
+    /// Returns `None` if the resulting byte pointer is null.
+    #[inline]
+    pub const fn wrapping_bit_offset(self, count : isize) -> Option<Self> {
+        Self::new(self.as_ptr().wrapping_bit_offset(count))
+    }
+
+    /// Adds an unsigned offset in bytes to a bit pointer using wrapping arithmetic.
+    ///
+    /// See [`BitPtrMut::wrapping_byte_add`].
+    ///
+    /// # Returns
+    /// Returns `None` if the resulting byte pointer is null.
+    #[inline]
+    pub const fn wrapping_byte_add(self, count : usize) -> Option<Self> {
+        Self::new(self.as_ptr().wrapping_byte_add(count))
+    }
+
+    /// Adds an unsigned offset in bits to a bit pointer using wrapping arithmetic.
+    ///
+    /// See [`BitPtrMut::wrapping_bit_add`].
+    ///
+    /// # Returns
+    /// Returns `None` if the resulting byte pointer is null.
+    #[inline]
+    pub const fn wrapping_bit_add(self, count : usize) -> Option<Self> {
+        Self::new(self.as_ptr().wrapping_bit_add(count))
+    }
+
+    /// Subtracts an unsigned offset in bytes from a bit pointer using wrapping arithmetic.
+    ///
+    /// See [`BitPtrMut::wrapping_byte_sub`].
+    ///
+    /// # Returns
+    /// Returns `None` if the resulting byte pointer is null.
+    #[inline]
+    pub const fn wrapping_byte_sub(self, count : usize) -> Option<Self> {
+        Self::new(self.as_ptr().wrapping_byte_sub(count))
+    }
+
+    /// Subtracts an unsigned offset in bits from a bit pointer using wrapping arithmetic.
+    ///
+    /// See [`BitPtrMut::wrapping_bit_sub`].
+    ///
+    /// # Returns
+    /// Returns `None` if the resulting byte pointer is null.
+    #[inline]
+    pub const fn wrapping_bit_sub(self, count : usize) -> Option<Self> {
+        Self::new(self.as_ptr().wrapping_bit_sub(count))
+    }
+
+    /// Adds a signed offset in bits to a bit pointer, checking that the address does not wrap around.
+    ///
+    /// See [`BitPtrMut::checked_bit_offset`].
+    ///
+    /// # Returns
+    /// Returns `None` if the address of the resulting byte would overflow or underflow, or if the resulting byte pointer is null.
+    #[inline]
+    pub fn checked_bit_offset(self, count : isize) -> Option<Self> {
+        self.as_ptr().checked_bit_offset(count).and_then(Self::new)
+    }
+
+    /// Adds an unsigned offset in bits to a bit pointer, checking that the address does not wrap around.
+    ///
+    /// See [`BitPtrMut::checked_bit_add`].
+    ///
+    /// # Returns
+    /// Returns `None` if the address of the resulting byte would overflow.
+    #[inline]
+    pub fn checked_bit_add(self, count : usize) -> Option<Self> {
+        self.as_ptr().checked_bit_add(count).and_then(Self::new)
+    }
+
+    /// Subtracts an unsigned offset in bits from a bit pointer, checking that the address does not wrap around.
+    ///
+    /// See [`BitPtrMut::checked_bit_sub`].
+    ///
+    /// # Returns
+    /// Returns `None` if the address of the resulting byte would underflow, or if the resulting byte pointer is null.
+    #[inline]
+    pub fn checked_bit_sub(self, count : usize) -> Option<Self> {
+        self.as_ptr().checked_bit_sub(count).and_then(Self::new)
+    }
+
+    /// Calculates the distance between two bit pointers, in a unit of **bits**, using wrapping arithmetic.
+    ///
+    /// See [`BitPtrMut::wrapping_bit_offset_from`].
+    #[inline]
+    pub fn wrapping_bit_offset_from(self, origin : Self) -> isize {
+        self.as_ptr().wrapping_bit_offset_from(origin.as_ptr())
+    }
+
+    /// Calculates the distance between two bit pointers, in a unit of **bits**, using wrapping arithmetic.
+    ///
+    /// See [`BitPtrMut::wrapping_bit_offset_from_unsigned`].
+    #[inline]
+    pub fn wrapping_bit_offset_from_unsigned(self, origin : Self) -> usize {
+        self.as_ptr().wrapping_bit_offset_from_unsigned(origin.as_ptr())
+    }
+
+    /// Calculates the distance between two bit pointers, in a unit of **bits**.
+    ///
+    /// See [`BitPtrMut::checked_bit_offset_from`].
+    #[inline]
+    pub fn checked_bit_offset_from(self, origin : Self) -> Option<isize> {
+        self.as_ptr().checked_bit_offset_from(origin.as_ptr())
+    }
+
+    /// Calculates the distance between two bit pointers, in a unit of **bits**, where `self` is expected to be at or after `origin`.
+    ///
+    /// See [`BitPtrMut::checked_bit_offset_from_unsigned`].
+    #[inline]
+    pub fn checked_bit_offset_from_unsigned(self, origin : Self) -> Option<usize> {
+        self.as_ptr().checked_bit_offset_from_unsigned(origin.as_ptr())
+    }
+
+}
+
+impl<O : BitOrder> NonNullBitPtr<O> {
+
+    /// Returns the address of the byte that is pointed to, without exposing its provenance.
+    ///
+    /// ---
+    /// Analagous to [`NonNull::addr`].
+    #[inline]
+    pub fn addr(self) -> NonZero<usize> { self.byte.addr() }
+
+    /// Creates a new bit pointer with the given byte address, keeping the provenance of `self` and the sub-byte bit offset.
+    ///
+    /// ---
+    /// Analagous to [`NonNull::with_addr`].
+    #[inline]
+    pub fn with_addr(self, addr : NonZero<usize>) -> Self {
+        Self { byte : self.byte.with_addr(addr), bit : self.bit, order : PhantomData }
+    }
+
+    /// Creates a new bit pointer by mapping the byte address of `self`, keeping the provenance of `self` and the sub-byte bit offset.
+    ///
+    /// ---
+    /// Analagous to [`NonNull::map_addr`].
+    #[inline]
+    pub fn map_addr(self, f : impl FnOnce(NonZero<usize>) -> NonZero<usize>) -> Self {
+        self.with_addr(f(self.addr()))
+    }
+
+}
+
+impl<O : BitOrder> NonNullBitPtr<O> {
+
+    /// Reads the bit that is pointed to.
+    ///
+    /// # Safety
+    /// See [`BitPtrMut::read`].
+    #[inline]
+    pub const unsafe fn read(self) -> bool {
+        unsafe { self.as_ptr().read() }
+    }
+
+    /// Reads the `width`-bit unsigned integer that is pointed to.
+    ///
+    /// # Panics
+    /// Panics if `width` is greater than `T::BITS`.
+    ///
+    /// # Safety
+    /// See [`BitPtrMut::read_bits`].
+    #[inline]
+    pub unsafe fn read_bits<T : Unsigned>(self, width : u32) -> T {
+        unsafe { self.as_ptr().read_bits(width) }
+    }
+
+    /// Reads the `width`-bit two's complement signed integer that is pointed to, sign-extending it.
+    ///
+    /// # Panics
+    /// Panics if `width` is greater than `T::BITS`.
+    ///
+    /// # Safety
+    /// See [`BitPtrMut::read_bits_signed`].
+    #[inline]
+    pub unsafe fn read_bits_signed<T : Signed>(self, width : u32) -> T {
+        unsafe { self.as_ptr().read_bits_signed(width) }
+    }
+
+    /// Sets the bit that is pointed to.
+    ///
+    /// # Safety
+    /// See [`BitPtrMut::write`].
+    #[inline]
+    pub const unsafe fn write(self, bit : bool) {
+        unsafe { self.as_ptr().write(bit) }
+    }
+
+    /// Writes the lowest `width` bits of `value` to the bit field that is pointed to.
+    ///
+    /// # Panics
+    /// Panics if `width` is greater than `T::BITS`.
+    ///
+    /// # Safety
+    /// See [`BitPtrMut::write_bits`].
+    #[inline]
+    pub unsafe fn write_bits<T : Unsigned>(self, value : T, width : u32) {
+        unsafe { self.as_ptr().write_bits(value, width) }
+    }
+
+    /// Writes the lowest `width` bits of the two's complement representation of `value` to the bit field that is pointed to.
+    ///
+    /// # Panics
+    /// Panics if `width` is greater than `T::BITS`.
+    ///
+    /// # Safety
+    /// See [`BitPtrMut::write_bits_signed`].
+    #[inline]
+    pub unsafe fn write_bits_signed<T : Signed>(self, value : T, width : u32) {
+        unsafe { self.as_ptr().write_bits_signed(value, width) }
+    }
+
+    /// Writes `value` as a `width`-bit two's complement integer to the bit field that is pointed to, if it fits.
+    ///
+    /// # Returns
+    /// Returns `false` without writing anything if `value` can not be represented in `width` bits.
+    ///
+    /// # Panics
+    /// Panics if `width` is greater than `T::BITS`.
+    ///
+    /// # Safety
+    /// See [`BitPtrMut::checked_write_bits_signed`].
+    #[must_use]
+    #[inline]
+    pub unsafe fn checked_write_bits_signed<T : Signed>(self, value : T, width : u32) -> bool {
+        unsafe { self.as_ptr().checked_write_bits_signed(value, width) }
+    }
+
+    /// Reads the bit that is pointed to, using a volatile read of its byte.
+    ///
+    /// # Safety
+    /// See [`BitPtrMut::read_volatile`].
+    #[inline]
+    pub unsafe fn read_volatile(self) -> bool {
+        unsafe { self.as_ptr().read_volatile() }
+    }
+
+    /// Reads the `width`-bit unsigned integer that is pointed to, using volatile reads.
+    ///
+    /// # Panics
+    /// Panics if `width` is greater than `T::BITS`.
+    ///
+    /// # Safety
+    /// See [`BitPtrMut::read_bits_volatile`].
+    #[inline]
+    pub unsafe fn read_bits_volatile<T : Unsigned>(self, width : u32) -> T {
+        unsafe { self.as_ptr().read_bits_volatile(width) }
+    }
+
+    /// Reads the `width`-bit two's complement signed integer that is pointed to, sign-extending it, using volatile reads.
+    ///
+    /// # Panics
+    /// Panics if `width` is greater than `T::BITS`.
+    ///
+    /// # Safety
+    /// See [`BitPtrMut::read_bits_signed_volatile`].
+    #[inline]
+    pub unsafe fn read_bits_signed_volatile<T : Signed>(self, width : u32) -> T {
+        unsafe { self.as_ptr().read_bits_signed_volatile(width) }
+    }
+
+    /// Sets the bit that is pointed to, using a volatile read and a volatile write of its byte.
+    ///
+    /// # Safety
+    /// See [`BitPtrMut::write_volatile`].
+    #[inline]
+    pub unsafe fn write_volatile(self, bit : bool) {
+        unsafe { self.as_ptr().write_volatile(bit) }
+    }
+
+    /// Writes the lowest `width` bits of `value` to the bit field that is pointed to, using volatile reads and writes.
+    ///
+    /// # Panics
+    /// Panics if `width` is greater than `T::BITS`.
+    ///
+    /// # Safety
+    /// See [`BitPtrMut::write_bits_volatile`].
+    #[inline]
+    pub unsafe fn write_bits_volatile<T : Unsigned>(self, value : T, width : u32) {
+        unsafe { self.as_ptr().write_bits_volatile(value, width) }
+    }
+
+    /// Writes the lowest `width` bits of the two's complement representation of `value` to the bit field that is pointed to, using volatile reads and writes.
+    ///
+    /// # Panics
+    /// Panics if `width` is greater than `T::BITS`.
+    ///
+    /// # Safety
+    /// See [`BitPtrMut::write_bits_signed_volatile`].
+    #[inline]
+    pub unsafe fn write_bits_signed_volatile<T : Signed>(self, value : T, width : u32) {
+        unsafe { self.as_ptr().write_bits_signed_volatile(value, width) }
+    }
+
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Lsb0;
+    use core::mem::size_of;
+
+
+    #[test]
+    fn nonnull_bitptr_niche() {
+        assert_eq!(size_of::<Option<SubByte>>(), size_of::<SubByte>());
+        assert_eq!(size_of::<Option<NonNullBitPtr>>(), size_of::<NonNullBitPtr>());
+        assert_eq!(size_of::<NonNullBitPtr>(), size_of::<BitPtrMut>());
+    }
+
+
+    #[test]
+    fn nonnull_bitptr_conversions() {
+        let mut x = [0u8; 2];
+        let xptr = unsafe { BitPtrMut::new_with_offset(x.as_mut_ptr(), 11) };
+
+        let nptr = NonNullBitPtr::try_from(xptr.as_const()).unwrap();
+        assert!(nptr.as_ptr() == xptr);
+        assert!(NonNullBitPtr::try_from(xptr) == Ok(nptr));
+        assert!(BitPtr::from(nptr) == xptr.as_const());
+        assert!(BitPtrMut::from(nptr) == xptr);
+        assert!(<NonNullBitPtr>::try_from(crate::null()) == Err(crate::null()));
+
+        let byte = NonNull::from(&mut x[1]);
+        assert!(NonNullBitPtr::from(byte) == unsafe { nptr.bit_sub(3) });
+        assert!(NonNullBitPtr::from(byte).as_inner() == (byte, SubByte::MIN,));
+    }
+
+
+    #[test]
+    fn nonnull_bitptr_new() {
+        assert!(NonNullBitPtr::new(crate::null_mut()).is_none());
+
+        let mut x = [0b01011011u8, 0b10010110];
+        let xptr = unsafe { BitPtrMut::new_with_offset(x.as_mut_ptr(), 3) };
+        let nptr = NonNullBitPtr::new(xptr).unwrap();
+        assert!(nptr.as_ptr() == xptr);
+        assert!(BitPtr::from(nptr) == xptr.as_const());
+        assert_eq!(nptr.subbyte_bit().get(), 3);
+    }
+
+
+    #[test]
+    fn nonnull_bitptr_read_write() {
+        let mut x = [0b01011011u8, 0b10010110];
+
+        let nptr = NonNullBitPtr::new(BitPtrMut::new_on_byte(x.as_mut_ptr())).unwrap();
+        assert_eq!(unsafe { nptr.bit_add(3).read_bits::<u16>(10) }, 0b1101110010);
+        assert_eq!(unsafe { nptr.bit_add(13).bit_offset_from(nptr) }, 13);
+        assert_eq!(unsafe { nptr.bit_add(9).bit_sub(2).read() }, true);
+        unsafe { nptr.bit_add(1).write(false); }
+        unsafe { nptr.cast_order::<Lsb0>().byte_add(1).write_bits::<u8>(0b1001, 4); }
+        assert_eq!(x, [0b00011011u8, 0b10011001]);
+    }
+
+
+    #[test]
+    fn nonnull_bitptr_arithmetic() {
+        assert!(<NonNullBitPtr>::try_from(crate::null_mut()) == Err(crate::null_mut()));
+
+        let mut x = [0u8; 4];
+        let xptr = unsafe { BitPtrMut::new_with_offset(x.as_mut_ptr(), 5) };
+        let nptr = NonNullBitPtr::try_from(xptr).unwrap();
+        assert!(nptr.wrapping_bit_add(11) == NonNullBitPtr::new(xptr.wrapping_bit_add(11)));
+        assert!(nptr.wrapping_bit_offset(-5) == NonNullBitPtr::new(xptr.wrapping_bit_offset(-5)));
+        assert!(nptr.wrapping_byte_sub(1).unwrap().wrapping_byte_add(1) == Some(nptr));
+        assert!(nptr.checked_bit_add(20).unwrap().checked_bit_sub(20) == Some(nptr));
+        assert_eq!(nptr.checked_bit_offset(19).unwrap().checked_bit_offset_from(nptr), Some(19));
+        assert_eq!(nptr.wrapping_bit_offset_from_unsigned(nptr.checked_bit_add(3).unwrap()), usize::MAX - 2);
+        assert!(nptr.map_addr(|addr| addr.checked_add(1).unwrap()) == unsafe { nptr.byte_add(1) });
+        assert_eq!(nptr.with_addr(nptr.addr()).subbyte_bit().get(), 5);
+
+        // Results which would be null are rejected.
+        let low = NonNullBitPtr::new(BitPtrMut::new(core::ptr::without_provenance_mut::<u8>(1), SubByte::MIN)).unwrap();
+        assert!(low.wrapping_bit_sub(8).is_none());
+        assert!(low.checked_bit_sub(8).is_none());
+        assert!(low.checked_bit_sub(1).is_none());
+        assert!(low.checked_bit_sub(16).is_none());
+        assert!(low.wrapping_bit_sub(16).is_some());
+    }
+
+
+    #[test]
+    fn nonnull_bitptr_add_sub() {
+        let mut x = [0u8; 4];
+        let nptr = NonNullBitPtr::new(BitPtrMut::new_on_byte(x.as_mut_ptr())).unwrap();
+
+        for count in 0..32 {
+            assert!(nptr + count == unsafe { nptr.bit_add(count) });
+            assert!((nptr + count) - count == nptr);
+        }
+        let mut ptr = nptr;
+        ptr += 19;
+        ptr -= 10;
+        assert!(ptr == unsafe { nptr.bit_add(9) });
+    }
+
+
+    #[test]
+    #[should_panic(expected = "bit pointer overflow")]
+    fn nonnull_bitptr_add_overflow() {
+        let high = NonNullBitPtr::new(BitPtrMut::new(core::ptr::without_provenance_mut::<u8>(usize::MAX), SubByte::MAX)).unwrap();
+        let _ = high + 1;
+    }
+
+
+    #[test]
+    #[should_panic(expected = "bit pointer underflow")]
+    fn nonnull_bitptr_sub_underflow() {
+        let mut low = NonNullBitPtr::new(BitPtrMut::new(core::ptr::without_provenance_mut::<u8>(1), SubByte::MIN)).unwrap();
+        low -= 1;
+    }
+
+}
@@ -1,16 +1,27 @@
+use core::fmt;
+
+
 /// A sub-byte offset.
+///
+/// Only the values `0..8` are valid, so `Option<SubByte>` is the same size as `SubByte`.
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct SubByte {
-    bit : u8
+    bit : Bit
 }
 
+/// The valid values of a [`SubByte`].
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(u8)]
+#[allow(dead_code)] // Variants are constructed by `SubByte::new_unchecked`.
+enum Bit { B0 = 0, B1 = 1, B2 = 2, B3 = 3, B4 = 4, B5 = 5, B6 = 6, B7 = 7 }
+
 impl SubByte {
 
     /// The minimum sub-byte offset value (`0`).
-    pub const MIN : Self = unsafe { Self::new_unchecked(0) };
+    pub const MIN : Self = Self { bit : Bit::B0 };
 
     /// The maximum sub-byte offset value (`7`).
-    pub const MAX : Self = unsafe { Self::new_unchecked(7) };
+    pub const MAX : Self = Self { bit : Bit::B7 };
 
     /// Create a new `SubByte` from a sub-byte offset.
     ///
@@ -19,7 +30,7 @@ impl SubByte {
     #[inline]
     pub const fn new(bit : u8) -> Option<Self> {
         if (bit >= 8) { None }
-        else { Some(Self { bit }) }
+        else { Some(unsafe { Self::new_unchecked(bit) }) }
     }
 
     /// Create a new `SubByte` from a sub-byte offset, without checking if it is valid.
@@ -27,10 +38,18 @@ impl SubByte {
     /// # Safety
     /// Behaviour is undefined if `bit` is not **less** than `8`.
     #[inline(always)]
-    pub const unsafe fn new_unchecked(bit : u8) -> Self { Self { bit } }
+    pub const unsafe fn new_unchecked(bit : u8) -> Self {
+        Self { bit : unsafe { core::mem::transmute::<u8, Bit>(bit) } }
+    }
 
     /// Returns the sub-byte offset as a `u8`.
     #[inline]
-    pub const fn get(&self) -> u8 { self.bit }
+    pub const fn get(&self) -> u8 { self.bit as u8 }
+
+}
 
+impl fmt::Debug for SubByte {
+    fn fmt(&self, f : &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("SubByte").field(&self.get()).finish()
+    }
 }
@@ -0,0 +1,294 @@
+use crate::{ BitPtr, BitOrder, Msb0, BitSlice, BitSliceMut, Unsigned, Signed };
+use core::marker::PhantomData;
+use core::fmt;
+
+
+/// A cursor which reads bits from the front of a bit slice.
+///
+/// Reading past the end of the bit slice returns an [`UnexpectedEnd`] error, and does not advance the cursor.
+///
+/// ```rust
+/// use bitptr::BitReader;
+///
+/// let x = [0b_10111010_u8, 0b_11110000_u8];
+///
+/// let mut reader = BitReader::new(&x);
+/// assert_eq!(reader.read_bool(), Ok(true));
+/// assert_eq!(reader.read_bits::<u8>(3), Ok(0b_011));
+/// assert_eq!(reader.read_bits_signed::<i8>(6), Ok(-0b_10101));
+/// reader.align_to_byte().unwrap();
+/// assert_eq!(reader.remaining(), 0);
+/// assert!(reader.read_bool().is_err());
+/// ```
+#[derive(Clone)]
+pub struct BitReader<'a, O : BitOrder = Msb0> {
+    ptr       : BitPtr<O>,
+    position  : usize,
+    remaining : usize,
+    bytes     : PhantomData<&'a [u8]>
+}
+
+impl<'a> BitReader<'a> {
+
+    /// Create a new reader over all bits of `bytes`.
+    ///
+    /// The new reader uses [`Msb0`] bit order. See [`cast_order`](Self::cast_order) to use another bit order.
+    #[inline]
+    pub fn new(bytes : &'a [u8]) -> Self {
+        Self::from_bitslice(BitSlice::new(bytes))
+    }
+
+}
+
+impl<'a, O : BitOrder> BitReader<'a, O> {
+
+    /// Create a new reader over all bits of `bits`.
+    #[inline]
+    pub const fn from_bitslice(bits : BitSlice<'a, O>) -> Self {
+        Self { ptr : bits.as_bitptr(), position : 0, remaining : bits.len(), bytes : PhantomData }
+    }
+
+    /// Changes the bit order of this reader, keeping the same position.
+    ///
+    /// See [`BitPtr::cast_order`].
+    #[inline]
+    pub const fn cast_order<P : BitOrder>(self) -> BitReader<'a, P> {
+        BitReader { ptr : self.ptr.cast_order(), position : self.position, remaining : self.remaining, bytes : PhantomData }
+    }
+
+    /// Returns the number of bits that have been read or skipped.
+    #[inline(always)]
+    pub const fn position(&self) -> usize { self.position }
+
+    /// Returns the number of bits that have not yet been read.
+    #[inline(always)]
+    pub const fn remaining(&self) -> usize { self.remaining }
+
+    /// Returns `true` if there are no bits left to read.
+    #[inline(always)]
+    pub const fn is_empty(&self) -> bool { self.remaining == 0 }
+
+    /// Returns the raw bit pointer to the next bit to be read.
+    #[inline(always)]
+    pub const fn as_bitptr(&self) -> BitPtr<O> { self.ptr }
+
+    /// Returns a bit slice over the bits that have not yet been read.
+    #[inline]
+    pub const fn as_bitslice(&self) -> BitSlice<'a, O> {
+        unsafe { BitSlice::from_raw_parts(self.ptr, self.remaining) }
+    }
+
+    /// Returns an error if fewer than `bit_count` bits remain.
+    #[inline]
+    const fn require(&self, bit_count : usize) -> Result<(), UnexpectedEnd> {
+        if (bit_count > self.remaining) {
+            Err(UnexpectedEnd { requested : bit_count, remaining : self.remaining })
+        } else { Ok(()) }
+    }
+
+    /// Advances past `bit_count` bits, without checking that they remain.
+    #[inline]
+    const unsafe fn advance_unchecked(&mut self, bit_count : usize) {
+        self.ptr        = unsafe { self.ptr.bit_add(bit_count) };
+        self.position  += bit_count;
+        self.remaining -= bit_count;
+    }
+
+    /// Skips `bit_count` bits.
+    pub const fn skip(&mut self, bit_count : usize) -> Result<(), UnexpectedEnd> {
+        if let Err(err) = self.require(bit_count) { return Err(err); }
+        unsafe { self.advance_unchecked(bit_count); }
+        Ok(())
+    }
+
+    /// Skips to the next bit which is at the start of a byte in memory.
+    ///
+    /// If the next bit is already at the start of a byte, this has no effect.
+    pub const fn align_to_byte(&mut self) -> Result<(), UnexpectedEnd> {
+        let bit = self.ptr.subbyte_bit().get();
+        if (bit == 0) { return Ok(()); }
+        self.skip((8 - bit) as usize)
+    }
+
+    /// Returns the next bit without advancing.
+    pub const fn peek_bool(&self) -> Result<bool, UnexpectedEnd> {
+        if let Err(err) = self.require(1) { return Err(err); }
+        Ok(unsafe { self.ptr.read() })
+    }
+
+    /// Reads the next bit.
+    pub const fn read_bool(&mut self) -> Result<bool, UnexpectedEnd> {
+        let value = self.peek_bool();
+        if (value.is_ok()) {
+            unsafe { self.advance_unchecked(1); }
+        }
+        value
+    }
+
+    /// Returns the next `width` bits as an unsigned integer without advancing.
+    ///
+    /// See [`BitPtr::read_bits`].
+    ///
+    /// # Panics
+    /// Panics if `width` is greater than `T::BITS`.
+    pub fn peek_bits<T : Unsigned>(&self, width : u32) -> Result<T, UnexpectedEnd> {
+        self.require(width as usize)?;
+        Ok(unsafe { self.ptr.read_bits(width) })
+    }
+
+    /// Reads the next `width` bits as an unsigned integer.
+    ///
+    /// See [`BitPtr::read_bits`].
+    ///
+    /// # Panics
+    /// Panics if `width` is greater than `T::BITS`.
+    pub fn read_bits<T : Unsigned>(&mut self, width : u32) -> Result<T, UnexpectedEnd> {
+        let value = self.peek_bits(width)?;
+        unsafe { self.advance_unchecked(width as usize); }
+        Ok(value)
+    }
+
+    /// Returns the next `width` bits as a sign-extended signed integer without advancing.
+    ///
+    /// See [`BitPtr::read_bits_signed`].
+    ///
+    /// # Panics
+    /// Panics if `width` is greater than `T::BITS`.
+    pub fn peek_bits_signed<T : Signed>(&self, width : u32) -> Result<T, UnexpectedEnd> {
+        self.require(width as usize)?;
+        Ok(unsafe { self.ptr.read_bits_signed(width) })
+    }
+
+    /// Reads the next `width` bits as a sign-extended signed integer.
+    ///
+    /// See [`BitPtr::read_bits_signed`].
+    ///
+    /// # Panics
+    /// Panics if `width` is greater than `T::BITS`.
+    pub fn read_bits_signed<T : Signed>(&mut self, width : u32) -> Result<T, UnexpectedEnd> {
+        let value = self.peek_bits_signed(width)?;
+        unsafe { self.advance_unchecked(width as usize); }
+        Ok(value)
+    }
+
+    /// Reads the next `bit_count` bits as a bit slice.
+    pub fn read_bitslice(&mut self, bit_count : usize) -> Result<BitSlice<'a, O>, UnexpectedEnd> {
+        self.require(bit_count)?;
+        let bits = unsafe { BitSlice::from_raw_parts(self.ptr, bit_count) };
+        unsafe { self.advance_unchecked(bit_count); }
+        Ok(bits)
+    }
+
+    /// Reads the next `dst.len()` bits, copying them into `dst`.
+    ///
+    /// `dst` may use a different [bit order](BitOrder).
+    pub fn read_into<P : BitOrder>(&mut self, dst : &mut BitSliceMut<'_, P>) -> Result<(), UnexpectedEnd> {
+        self.require(dst.len())?;
+        unsafe { crate::copy_nonoverlapping(self.ptr, dst.as_bitptr_mut(), dst.len()); }
+        unsafe { self.advance_unchecked(dst.len()); }
+        Ok(())
+    }
+
+    /// Reads the next `bytes.len()` bytes, copying them into `bytes`.
+    pub fn read_bytes(&mut self, bytes : &mut [u8]) -> Result<(), UnexpectedEnd> {
+        self.read_into(&mut BitSliceMut::new(bytes).cast_order::<O>())
+    }
+
+}
+
+
+/// The error returned by a [`BitReader`] when more bits were requested than remain.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct UnexpectedEnd {
+    requested : usize,
+    remaining : usize
+}
+
+impl UnexpectedEnd {
+
+    /// Returns the number of bits that were requested.
+    #[inline(always)]
+    pub const fn requested(&self) -> usize { self.requested }
+
+    /// Returns the number of bits that remained in the reader.
+    #[inline(always)]
+    pub const fn remaining(&self) -> usize { self.remaining }
+
+}
+
+impl fmt::Display for UnexpectedEnd {
+    fn fmt(&self, f : &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unexpected end of bits: requested {} bits, but only {} remain", self.requested, self.remaining)
+    }
+}
+
+impl core::error::Error for UnexpectedEnd { }
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Lsb0;
+
+
+    #[test]
+    fn reader_read_fields() {
+        let x = [0b01011011u8, 0b10010110, 0b11100001];
+
+        let mut r = BitReader::new(&x);
+        assert_eq!(r.remaining(), 24);
+        assert_eq!(r.read_bits::<u8>(3), Ok(0b010));
+        assert_eq!(r.peek_bool(), Ok(true));
+        assert_eq!(r.read_bits::<u16>(10), Ok(0b1101110010));
+        assert_eq!(r.position(), 13);
+        assert_eq!(r.read_bits_signed::<i8>(4), Ok(-0b0011));
+        assert_eq!(r.peek_bits::<u8>(8), Err(UnexpectedEnd { requested : 8, remaining : 7 }));
+        assert_eq!(r.read_bits::<u8>(7), Ok(0b1100001));
+        assert!(r.is_empty());
+        assert_eq!(r.read_bool(), Err(UnexpectedEnd { requested : 1, remaining : 0 }));
+        assert_eq!(r.read_bits::<u8>(0), Ok(0));
+    }
+
+
+    #[test]
+    fn reader_skip_align() {
+        let x = [0b01011011u8, 0b10010110, 0b11100001];
+
+        let mut r = BitReader::from_bitslice(BitSlice::new_range(&x, 2..15).unwrap());
+        assert_eq!(r.align_to_byte(), Ok(()));
+        assert_eq!(r.position(), 6);
+        assert_eq!(r.align_to_byte(), Ok(()));
+        assert_eq!(r.position(), 6);
+        assert_eq!(r.skip(5), Ok(()));
+        assert_eq!(r.read_bool(), Ok(true));
+        assert_eq!(r.align_to_byte(), Err(UnexpectedEnd { requested : 2, remaining : 1 }));
+        assert_eq!(r.position(), 12);
+        assert_eq!(r.skip(2), Err(UnexpectedEnd { requested : 2, remaining : 1 }));
+        assert_eq!(r.read_bool(), Ok(true));
+    }
+
+
+    #[test]
+    fn reader_read_into() {
+        let x = [0b01011011u8, 0b10010110, 0b11100001];
+
+        let mut r = BitReader::new(&x);
+        r.skip(7).unwrap();
+        let mut y = [0u8; 2];
+        assert_eq!(r.read_bytes(&mut y), Ok(()));
+        assert_eq!(y, [0b11001011, 0b01110000]);
+        assert_eq!(r.read_bytes(&mut y), Err(UnexpectedEnd { requested : 16, remaining : 1 }));
+        assert_eq!(r.read_bitslice(1).map(|bits| bits.get(0)), Ok(Some(true)));
+    }
+
+
+    #[test]
+    fn reader_lsb0() {
+        let x = [0b01011011u8, 0b10010110];
+
+        let mut r = BitReader::new(&x).cast_order::<Lsb0>();
+        assert_eq!(r.read_bits::<u8>(3), Ok(0b011));
+        assert_eq!(r.read_bits::<u16>(9), Ok(0b0110_01011));
+    }
+
+}
@@ -0,0 +1,162 @@
+use crate::{ BitPtrMut, BitOrder };
+
+
+/// Reverses the order of `bit_count` bits at `dst`, in place.
+///
+/// The first bit of the region is swapped with the last bit, the second bit with the second last bit, and so on.
+///
+/// # Safety
+/// Behaviour is undefined if `dst.floor_byte()` is not [valid](core::ptr#safety) for reads and writes of `((dst.subbyte_bit().get() as usize) + bit_count).div_ceil(8)` bytes.
+///
+/// # Examples
+/// ```rust
+/// use bitptr::BitPtrMut;
+///
+/// let mut x = [0b_01011011_u8, 0b_10010110_u8];
+/// //                  ^^^^^^^^^^^ This is the region that is reversed.
+///
+/// let xptr = unsafe { BitPtrMut::new_with_offset(x.as_mut_ptr(), 4) };
+///
+/// unsafe { bitptr::reverse(xptr, 11); }
+/// assert_eq!(x, [0b_01011101_u8, 0b_00111010_u8]);
+/// ```
+///
+/// ---
+/// Analagous to [`slice::reverse`](primitive@slice#method.reverse).
+pub unsafe fn reverse<O : BitOrder>(dst : BitPtrMut<O>, bit_count : usize) {
+    let mut front = 0;
+    let mut back  = bit_count;
+
+    // Swap whole words from each end, reversing them on the way.
+    while (back - front >= 128) {
+        let front_ptr = unsafe { dst.bit_add(front) };
+        let back_ptr  = unsafe { dst.bit_add(back - 64) };
+        let front_w   = unsafe { front_ptr.read_bits::<u64>(64) };
+        let back_w    = unsafe { back_ptr.read_bits::<u64>(64) };
+        unsafe { front_ptr.write_bits(back_w.reverse_bits(), 64); }
+        unsafe { back_ptr.write_bits(front_w.reverse_bits(), 64); }
+        front += 64;
+        back  -= 64;
+    }
+
+    // The bits that are left over fit in a single integer.
+    let width = back - front;
+    if (width > 0) {
+        let middle_ptr = unsafe { dst.bit_add(front) };
+        let middle     = unsafe { middle_ptr.read_bits::<u128>(width as u32) };
+        unsafe { middle_ptr.write_bits(middle.reverse_bits() >> (128 - width), width as u32); }
+    }
+}
+
+
+/// Rotates `bit_count` bits at `dst` in place, such that the bit at index `by` becomes the first bit.
+///
+/// # Safety
+/// Behaviour is undefined if `dst.floor_byte()` is not [valid](core::ptr#safety) for reads and writes of `((dst.subbyte_bit().get() as usize) + bit_count).div_ceil(8)` bytes.
+///
+/// # Panics
+/// Panics if `by` is greater than `bit_count`.
+///
+/// # Examples
+/// ```rust
+/// use bitptr::BitPtrMut;
+///
+/// let mut x = [0b_01011011_u8, 0b_10010110_u8];
+/// //                  ^^^^^^^^^^^ This is the region that is rotated.
+///
+/// let xptr = unsafe { BitPtrMut::new_with_offset(x.as_mut_ptr(), 4) };
+///
+/// unsafe { bitptr::rotate_left(xptr, 11, 3); }
+/// assert_eq!(x, [0b_01011100_u8, 0b_10111010_u8]);
+/// ```
+///
+/// ---
+/// Analagous to [`slice::rotate_left`](primitive@slice#method.rotate_left).
+pub unsafe fn rotate_left<O : BitOrder>(dst : BitPtrMut<O>, bit_count : usize, by : usize) {
+    assert!(by <= bit_count, "rotation is greater than the bit count");
+    // Reversing both halves and then the whole region moves each half to the other end, without changing its order.
+    unsafe { reverse(dst, by); }
+    unsafe { reverse(dst.bit_add(by), bit_count - by); }
+    unsafe { reverse(dst, bit_count); }
+}
+
+
+/// Rotates `bit_count` bits at `dst` in place, such that the first bit becomes the bit at index `by`.
+///
+/// # Safety
+/// Behaviour is undefined if `dst.floor_byte()` is not [valid](core::ptr#safety) for reads and writes of `((dst.subbyte_bit().get() as usize) + bit_count).div_ceil(8)` bytes.
+///
+/// # Panics
+/// Panics if `by` is greater than `bit_count`.
+///
+/// ---
+/// Analagous to [`slice::rotate_right`](primitive@slice#method.rotate_right).
+pub unsafe fn rotate_right<O : BitOrder>(dst : BitPtrMut<O>, bit_count : usize, by : usize) {
+    assert!(by <= bit_count, "rotation is greater than the bit count");
+    unsafe { rotate_left(dst, bit_count, bit_count - by); }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ BitPtr, Lsb0 };
+
+
+    const X : [u8; 40] = [
+        0b01011011, 0b10010110, 0b11100001, 0b00111100, 0b01011011, 0b10010110, 0b11100001, 0b00111100, 0b10101010, 0b01010101,
+        0b11001100, 0b00110011, 0b11110000, 0b00001111, 0b01011011, 0b10010110, 0b11100001, 0b00111100, 0b10101010, 0b01010101,
+        0b11100001, 0b00111100, 0b10101010, 0b01010101, 0b11001100, 0b00110011, 0b11110000, 0b00001111, 0b01011011, 0b10010110,
+        0b01011011, 0b10010110, 0b11100001, 0b00111100, 0b01011011, 0b10010110, 0b11100001, 0b00111100, 0b10101010, 0b01010101
+    ];
+
+
+    fn model<O : BitOrder>(ptr : BitPtr<O>, bit_count : usize) -> Vec<bool> {
+        (0..bit_count).map(|i| unsafe { ptr.bit_add(i).read() }).collect()
+    }
+
+
+    #[test]
+    fn reverse_unaligned() {
+        for start in 0..8 {
+            for bit_count in [0, 1, 2, 7, 8, 9, 63, 64, 65, 127, 128, 129, 200, 300] {
+                let mut x    = X;
+                let     xptr = unsafe { BitPtrMut::new_on_byte(x.as_mut_ptr()).cast_order::<Lsb0>().bit_add(start) };
+                let     old  = BitPtr::new_on_byte(X.as_ptr()).cast_order::<Lsb0>();
+
+                let mut expected = model(old, X.len() * 8);
+                expected[start..(start + bit_count)].reverse();
+
+                unsafe { reverse(xptr, bit_count); }
+                assert_eq!(model(old.with_addr(x.as_ptr().addr()), X.len() * 8), expected);
+            }
+        }
+    }
+
+
+    #[test]
+    fn rotate_unaligned() {
+        for start in 0..8 {
+            for bit_count in [0, 1, 9, 65, 200] {
+                for by in [0, 1, 5, 64, 100, 200].into_iter().filter(|&by| by <= bit_count) {
+                    let mut x    = X;
+                    let mut y    = X;
+                    let     xptr = unsafe { BitPtrMut::new_on_byte(x.as_mut_ptr()).bit_add(start) };
+                    let     yptr = unsafe { BitPtrMut::new_on_byte(y.as_mut_ptr()).bit_add(start) };
+                    let     old  = BitPtr::new_on_byte(X.as_ptr());
+
+                    let mut expected_l = model(old, X.len() * 8);
+                    let mut expected_r = expected_l.clone();
+                    expected_l[start..(start + bit_count)].rotate_left(by);
+                    expected_r[start..(start + bit_count)].rotate_right(by);
+
+                    unsafe { rotate_left(xptr, bit_count, by); }
+                    unsafe { rotate_right(yptr, bit_count, by); }
+                    assert_eq!(model(BitPtr::new_on_byte(x.as_ptr()), X.len() * 8), expected_l);
+                    assert_eq!(model(BitPtr::new_on_byte(y.as_ptr()), X.len() * 8), expected_r);
+                }
+            }
+        }
+    }
+
+}
@@ -0,0 +1,109 @@
+use crate::{ BitPtrMut, BitOrder };
+
+
+/// Shifts `bit_count` bits at `dst` towards the front by `by` bits, in place.
+///
+/// The first `by` bits of the region are discarded, and the last `by` bits of the region are filled with `fill_bit`.
+///
+/// # Safety
+/// Behaviour is undefined if `dst.floor_byte()` is not [valid](core::ptr#safety) for reads and writes of `((dst.subbyte_bit().get() as usize) + bit_count).div_ceil(8)` bytes.
+///
+/// # Panics
+/// Panics if `by` is greater than `bit_count`.
+///
+/// # Examples
+/// ```rust
+/// use bitptr::BitPtrMut;
+///
+/// let mut x = [0b_01011011_u8, 0b_10010110_u8];
+/// //                  ^^^^^^^^^^^ This is the region that is shifted.
+///
+/// let xptr = unsafe { BitPtrMut::new_with_offset(x.as_mut_ptr(), 4) };
+///
+/// unsafe { bitptr::shift_left(xptr, 11, 3, false); }
+/// assert_eq!(x, [0b_01011100_u8, 0b_10110000_u8]);
+/// ```
+pub unsafe fn shift_left<O : BitOrder>(dst : BitPtrMut<O>, bit_count : usize, by : usize, fill_bit : bool) {
+    assert!(by <= bit_count, "shift is greater than the bit count");
+    unsafe { crate::copy(dst.as_const().bit_add(by), dst, bit_count - by); }
+    unsafe { crate::fill(dst.bit_add(bit_count - by), by, fill_bit); }
+}
+
+
+/// Shifts `bit_count` bits at `dst` towards the back by `by` bits, in place.
+///
+/// The last `by` bits of the region are discarded, and the first `by` bits of the region are filled with `fill_bit`.
+///
+/// # Safety
+/// Behaviour is undefined if `dst.floor_byte()` is not [valid](core::ptr#safety) for reads and writes of `((dst.subbyte_bit().get() as usize) + bit_count).div_ceil(8)` bytes.
+///
+/// # Panics
+/// Panics if `by` is greater than `bit_count`.
+///
+/// # Examples
+/// ```rust
+/// use bitptr::BitPtrMut;
+///
+/// let mut x = [0b_01011011_u8, 0b_10010110_u8];
+/// //                  ^^^^^^^^^^^ This is the region that is shifted.
+///
+/// let xptr = unsafe { BitPtrMut::new_with_offset(x.as_mut_ptr(), 4) };
+///
+/// unsafe { bitptr::shift_right(xptr, 11, 3, true); }
+/// assert_eq!(x, [0b_01011111_u8, 0b_01110010_u8]);
+/// ```
+pub unsafe fn shift_right<O : BitOrder>(dst : BitPtrMut<O>, bit_count : usize, by : usize, fill_bit : bool) {
+    assert!(by <= bit_count, "shift is greater than the bit count");
+    unsafe { crate::copy(dst.as_const(), dst.bit_add(by), bit_count - by); }
+    unsafe { crate::fill(dst, by, fill_bit); }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ BitPtr, Lsb0 };
+
+
+    const X : [u8; 20] = [
+        0b01011011, 0b10010110, 0b11100001, 0b00111100, 0b01011011, 0b10010110, 0b11100001, 0b00111100, 0b10101010, 0b01010101,
+        0b11001100, 0b00110011, 0b11110000, 0b00001111, 0b01011011, 0b10010110, 0b11100001, 0b00111100, 0b10101010, 0b01010101
+    ];
+
+
+    fn model<O : BitOrder>(ptr : BitPtr<O>, bit_count : usize) -> Vec<bool> {
+        (0..bit_count).map(|i| unsafe { ptr.bit_add(i).read() }).collect()
+    }
+
+
+    #[test]
+    fn shift_unaligned() {
+        for start in 0..8 {
+            for bit_count in [0, 1, 9, 65, 150] {
+                for by in [0, 1, 5, 64, 100, 150].into_iter().filter(|&by| by <= bit_count) {
+                    for fill_bit in [false, true] {
+                        let mut x    = X;
+                        let mut y    = X;
+                        let     xptr = unsafe { BitPtrMut::new_on_byte(x.as_mut_ptr()).cast_order::<Lsb0>().bit_add(start) };
+                        let     yptr = unsafe { BitPtrMut::new_on_byte(y.as_mut_ptr()).cast_order::<Lsb0>().bit_add(start) };
+                        let     old  = BitPtr::new_on_byte(X.as_ptr()).cast_order::<Lsb0>();
+
+                        let mut expected_l = model(old, X.len() * 8);
+                        let mut expected_r = expected_l.clone();
+                        let     range      = start..(start + bit_count);
+                        expected_l[range.clone()].rotate_left(by);
+                        expected_l[(range.end - by)..range.end].fill(fill_bit);
+                        expected_r[range.clone()].rotate_right(by);
+                        expected_r[range.start..(range.start + by)].fill(fill_bit);
+
+                        unsafe { shift_left(xptr, bit_count, by, fill_bit); }
+                        unsafe { shift_right(yptr, bit_count, by, fill_bit); }
+                        assert_eq!(model(old.with_addr(x.as_ptr().addr()), X.len() * 8), expected_l);
+                        assert_eq!(model(old.with_addr(y.as_ptr().addr()), X.len() * 8), expected_r);
+                    }
+                }
+            }
+        }
+    }
+
+}
@@ -0,0 +1,15 @@
+//! Vectorised kernels for the whole bytes in the middle of bulk operations.
+//!
+//! Each kernel processes as many bytes from the start of its input as it can, and returns how many it processed.
+//!  The caller handles the rest with its scalar path. Without the `simd` feature, or on unsupported targets, no bytes are processed.
+
+
+#[cfg(all(feature = "simd", any(target_arch = "x86", target_arch = "x86_64")))]
+mod x86;
+#[cfg(all(feature = "simd", any(target_arch = "x86", target_arch = "x86_64")))]
+pub(crate) use x86::{ fill, copy_nonoverlapping, count_ones, combine };
+
+#[cfg(not(all(feature = "simd", any(target_arch = "x86", target_arch = "x86_64"))))]
+mod scalar;
+#[cfg(not(all(feature = "simd", any(target_arch = "x86", target_arch = "x86_64"))))]
+pub(crate) use scalar::{ fill, copy_nonoverlapping, count_ones, combine };
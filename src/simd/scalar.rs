@@ -0,0 +1,45 @@
+use crate::bitwise::Op;
+
+
+/// Sets the first bytes of the `len` bytes at `dst` to `byte`.
+///
+/// # Returns
+/// Returns the number of bytes that were set.
+///
+/// # Safety
+/// Behaviour is undefined if `dst` is not [valid](core::ptr#safety) for writes of `len` bytes.
+#[inline(always)]
+pub(crate) unsafe fn fill(_dst : *mut u8, _len : usize, _byte : u8) -> usize { 0 }
+
+
+/// Copies the first bytes of the `len` bytes at `src` to `dst`.
+///
+/// # Returns
+/// Returns the number of bytes that were copied.
+///
+/// # Safety
+/// Behaviour is undefined if `src` is not [valid](core::ptr#safety) for reads of `len` bytes, if `dst` is not valid for writes of `len` bytes, or if the two regions overlap.
+#[inline(always)]
+pub(crate) unsafe fn copy_nonoverlapping(_src : *const u8, _dst : *mut u8, _len : usize) -> usize { 0 }
+
+
+/// Counts the `1` bits in the first bytes of the `len` bytes at `src`.
+///
+/// # Returns
+/// Returns the number of bytes that were counted, and the number of `1` bits in them.
+///
+/// # Safety
+/// Behaviour is undefined if `src` is not [valid](core::ptr#safety) for reads of `len` bytes.
+#[inline(always)]
+pub(crate) unsafe fn count_ones(_src : *const u8, _len : usize) -> (usize, usize,) { (0, 0,) }
+
+
+/// Combines the first bytes of the `len` bytes at `src` into `dst` using `op`.
+///
+/// # Returns
+/// Returns the number of bytes that were combined.
+///
+/// # Safety
+/// Behaviour is undefined if `src` is not [valid](core::ptr#safety) for reads of `len` bytes, or if `dst` is not valid for reads and writes of `len` bytes.
+#[inline(always)]
+pub(crate) unsafe fn combine(_src : *const u8, _dst : *mut u8, _len : usize, _op : Op) -> usize { 0 }
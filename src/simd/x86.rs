@@ -0,0 +1,331 @@
+use crate::bitwise::Op;
+#[cfg(target_arch = "x86")]
+use core::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+
+
+/// Returns `true` if AVX2 instructions can be used.
+///
+/// With the `std` feature, this is detected at runtime. Otherwise, it depends on the features that the crate was compiled with.
+#[inline(always)]
+fn has_avx2() -> bool {
+    #[cfg(feature = "std")]
+    { std::is_x86_feature_detected!("avx2") }
+    #[cfg(not(feature = "std"))]
+    { cfg!(target_feature = "avx2") }
+}
+
+/// Returns `true` if SSE2 instructions can be used.
+///
+/// See [`has_avx2`].
+#[inline(always)]
+fn has_sse2() -> bool {
+    #[cfg(feature = "std")]
+    { std::is_x86_feature_detected!("sse2") }
+    #[cfg(not(feature = "std"))]
+    { cfg!(target_feature = "sse2") }
+}
+
+
+/// Sets the first bytes of the `len` bytes at `dst` to `byte`.
+///
+/// # Returns
+/// Returns the number of bytes that were set.
+///
+/// # Safety
+/// Behaviour is undefined if `dst` is not [valid](core::ptr#safety) for writes of `len` bytes.
+#[inline]
+pub(crate) unsafe fn fill(dst : *mut u8, len : usize, byte : u8) -> usize {
+    if (has_avx2()) { unsafe { fill_avx2(dst, len, byte) } }
+    else if (has_sse2()) { unsafe { fill_sse2(dst, len, byte) } }
+    else { 0 }
+}
+
+#[target_feature(enable = "avx2")]
+unsafe fn fill_avx2(dst : *mut u8, len : usize, byte : u8) -> usize {
+    let value = _mm256_set1_epi8(byte as i8);
+    let mut offset = 0;
+    while (offset + 32 <= len) {
+        unsafe { _mm256_storeu_si256(dst.add(offset).cast(), value); }
+        offset += 32;
+    }
+    offset
+}
+
+#[target_feature(enable = "sse2")]
+unsafe fn fill_sse2(dst : *mut u8, len : usize, byte : u8) -> usize {
+    let value = _mm_set1_epi8(byte as i8);
+    let mut offset = 0;
+    while (offset + 16 <= len) {
+        unsafe { _mm_storeu_si128(dst.add(offset).cast(), value); }
+        offset += 16;
+    }
+    offset
+}
+
+
+/// Copies the first bytes of the `len` bytes at `src` to `dst`.
+///
+/// # Returns
+/// Returns the number of bytes that were copied.
+///
+/// # Safety
+/// Behaviour is undefined if `src` is not [valid](core::ptr#safety) for reads of `len` bytes, if `dst` is not valid for writes of `len` bytes, or if the two regions overlap.
+#[inline]
+pub(crate) unsafe fn copy_nonoverlapping(src : *const u8, dst : *mut u8, len : usize) -> usize {
+    if (has_avx2()) { unsafe { copy_nonoverlapping_avx2(src, dst, len) } }
+    else if (has_sse2()) { unsafe { copy_nonoverlapping_sse2(src, dst, len) } }
+    else { 0 }
+}
+
+#[target_feature(enable = "avx2")]
+unsafe fn copy_nonoverlapping_avx2(src : *const u8, dst : *mut u8, len : usize) -> usize {
+    let mut offset = 0;
+    while (offset + 32 <= len) {
+        unsafe { _mm256_storeu_si256(dst.add(offset).cast(), _mm256_loadu_si256(src.add(offset).cast())); }
+        offset += 32;
+    }
+    offset
+}
+
+#[target_feature(enable = "sse2")]
+unsafe fn copy_nonoverlapping_sse2(src : *const u8, dst : *mut u8, len : usize) -> usize {
+    let mut offset = 0;
+    while (offset + 16 <= len) {
+        unsafe { _mm_storeu_si128(dst.add(offset).cast(), _mm_loadu_si128(src.add(offset).cast())); }
+        offset += 16;
+    }
+    offset
+}
+
+
+/// Counts the `1` bits in the first bytes of the `len` bytes at `src`.
+///
+/// # Returns
+/// Returns the number of bytes that were counted, and the number of `1` bits in them.
+///
+/// # Safety
+/// Behaviour is undefined if `src` is not [valid](core::ptr#safety) for reads of `len` bytes.
+#[inline]
+pub(crate) unsafe fn count_ones(src : *const u8, len : usize) -> (usize, usize,) {
+    if (has_avx2()) { unsafe { count_ones_avx2(src, len) } }
+    else if (has_sse2()) { unsafe { count_ones_sse2(src, len) } }
+    else { (0, 0,) }
+}
+
+#[target_feature(enable = "avx2")]
+unsafe fn count_ones_avx2(src : *const u8, len : usize) -> (usize, usize,) {
+    // The number of `1` bits in each nibble is looked up in a table, and the per-byte counts are summed into 64 bit lanes.
+    let table = _mm256_setr_epi8(
+        0, 1, 1, 2, 1, 2, 2, 3, 1, 2, 2, 3, 2, 3, 3, 4,
+        0, 1, 1, 2, 1, 2, 2, 3, 1, 2, 2, 3, 2, 3, 3, 4
+    );
+    let nibble = _mm256_set1_epi8(0x0F);
+    let mut sum    = _mm256_setzero_si256();
+    let mut offset = 0;
+    while (offset + 32 <= len) {
+        let value = unsafe { _mm256_loadu_si256(src.add(offset).cast()) };
+        let lo    = _mm256_shuffle_epi8(table, _mm256_and_si256(value, nibble));
+        let hi    = _mm256_shuffle_epi8(table, _mm256_and_si256(_mm256_srli_epi16::<4>(value), nibble));
+        sum = _mm256_add_epi64(sum, _mm256_sad_epu8(_mm256_add_epi8(lo, hi), _mm256_setzero_si256()));
+        offset += 32;
+    }
+    let mut lanes = [0u64; 4];
+    unsafe { _mm256_storeu_si256(lanes.as_mut_ptr().cast(), sum); }
+    (offset, lanes.iter().sum::<u64>() as usize,)
+}
+
+#[target_feature(enable = "sse2")]
+unsafe fn count_ones_sse2(src : *const u8, len : usize) -> (usize, usize,) {
+    // The `1` bits of each byte are counted in parallel, and the per-byte counts are summed into 64 bit lanes.
+    let m1 = _mm_set1_epi8(0x55);
+    let m2 = _mm_set1_epi8(0x33);
+    let m4 = _mm_set1_epi8(0x0F);
+    let mut sum    = _mm_setzero_si128();
+    let mut offset = 0;
+    while (offset + 16 <= len) {
+        let mut value = unsafe { _mm_loadu_si128(src.add(offset).cast()) };
+        value = _mm_sub_epi8(value, _mm_and_si128(_mm_srli_epi64::<1>(value), m1));
+        value = _mm_add_epi8(_mm_and_si128(value, m2), _mm_and_si128(_mm_srli_epi64::<2>(value), m2));
+        value = _mm_and_si128(_mm_add_epi8(value, _mm_srli_epi64::<4>(value)), m4);
+        sum = _mm_add_epi64(sum, _mm_sad_epu8(value, _mm_setzero_si128()));
+        offset += 16;
+    }
+    let mut lanes = [0u64; 2];
+    unsafe { _mm_storeu_si128(lanes.as_mut_ptr().cast(), sum); }
+    (offset, lanes.iter().sum::<u64>() as usize,)
+}
+
+
+/// Combines the first bytes of the `len` bytes at `src` into `dst` using `op`.
+///
+/// # Returns
+/// Returns the number of bytes that were combined.
+///
+/// # Safety
+/// Behaviour is undefined if `src` is not [valid](core::ptr#safety) for reads of `len` bytes, or if `dst` is not valid for reads and writes of `len` bytes.
+#[inline]
+pub(crate) unsafe fn combine(src : *const u8, dst : *mut u8, len : usize, op : Op) -> usize {
+    if (has_avx2()) { unsafe { combine_avx2(src, dst, len, op) } }
+    else if (has_sse2()) { unsafe { combine_sse2(src, dst, len, op) } }
+    else { 0 }
+}
+
+#[target_feature(enable = "avx2")]
+unsafe fn combine_avx2(src : *const u8, dst : *mut u8, len : usize, op : Op) -> usize {
+    let mut offset = 0;
+    while (offset + 32 <= len) {
+        let src_v = unsafe { _mm256_loadu_si256(src.add(offset).cast()) };
+        let dst_v = unsafe { _mm256_loadu_si256(dst.add(offset).cast()) };
+        let value = match (op) {
+            Op::And    => _mm256_and_si256(dst_v, src_v),
+            Op::Or     => _mm256_or_si256(dst_v, src_v),
+            Op::Xor    => _mm256_xor_si256(dst_v, src_v),
+            Op::AndNot => _mm256_andnot_si256(src_v, dst_v)
+        };
+        unsafe { _mm256_storeu_si256(dst.add(offset).cast(), value); }
+        offset += 32;
+    }
+    offset
+}
+
+#[target_feature(enable = "sse2")]
+unsafe fn combine_sse2(src : *const u8, dst : *mut u8, len : usize, op : Op) -> usize {
+    let mut offset = 0;
+    while (offset + 16 <= len) {
+        let src_v = unsafe { _mm_loadu_si128(src.add(offset).cast()) };
+        let dst_v = unsafe { _mm_loadu_si128(dst.add(offset).cast()) };
+        let value = match (op) {
+            Op::And    => _mm_and_si128(dst_v, src_v),
+            Op::Or     => _mm_or_si128(dst_v, src_v),
+            Op::Xor    => _mm_xor_si128(dst_v, src_v),
+            Op::AndNot => _mm_andnot_si128(src_v, dst_v)
+        };
+        unsafe { _mm_storeu_si128(dst.add(offset).cast(), value); }
+        offset += 16;
+    }
+    offset
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ BitPtr, BitPtrMut, Lsb0 };
+
+
+    /// A small deterministic random number generator, so that failures are reproducible.
+    struct XorShift(u64);
+
+    impl XorShift {
+        fn next(&mut self) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0
+        }
+        fn bytes(&mut self, len : usize) -> Vec<u8> {
+            (0..len).map(|_| self.next() as u8).collect()
+        }
+    }
+
+
+    #[test]
+    fn kernels_match_scalar() {
+        let mut rng = XorShift(0x2545_F491_4F6C_DD1D);
+        for len in [0, 1, 15, 16, 17, 31, 32, 33, 100, 1000] {
+            let src = rng.bytes(len);
+            let dst = rng.bytes(len);
+            let ones = src.iter().map(|b| b.count_ones() as usize).sum::<usize>();
+
+            let mut levels = Vec::new();
+            if (is_x86_feature_detected!("sse2")) { levels.push(false); }
+            if (is_x86_feature_detected!("avx2")) { levels.push(true); }
+            for avx2 in levels {
+                let width = if (avx2) { 32 } else { 16 };
+                let handled = len / width * width;
+
+                let mut x = dst.clone();
+                assert_eq!(unsafe { if (avx2) { fill_avx2(x.as_mut_ptr(), len, 0xA5) } else { fill_sse2(x.as_mut_ptr(), len, 0xA5) } }, handled);
+                assert!(x[..handled].iter().all(|&b| b == 0xA5));
+                assert_eq!(x[handled..], dst[handled..]);
+
+                let mut x = dst.clone();
+                assert_eq!(unsafe { if (avx2) { copy_nonoverlapping_avx2(src.as_ptr(), x.as_mut_ptr(), len) } else { copy_nonoverlapping_sse2(src.as_ptr(), x.as_mut_ptr(), len) } }, handled);
+                assert_eq!(x[..handled], src[..handled]);
+                assert_eq!(x[handled..], dst[handled..]);
+
+                let (counted, counted_ones,) = unsafe { if (avx2) { count_ones_avx2(src.as_ptr(), len) } else { count_ones_sse2(src.as_ptr(), len) } };
+                assert_eq!(counted, handled);
+                assert_eq!(counted_ones + src[handled..].iter().map(|b| b.count_ones() as usize).sum::<usize>(), ones);
+
+                for op in [Op::And, Op::Or, Op::Xor, Op::AndNot] {
+                    let mut x = dst.clone();
+                    assert_eq!(unsafe { if (avx2) { combine_avx2(src.as_ptr(), x.as_mut_ptr(), len, op) } else { combine_sse2(src.as_ptr(), x.as_mut_ptr(), len, op) } }, handled);
+                    for i in 0..len {
+                        let expected = if (i < handled) { op.apply(dst[i] as u64, src[i] as u64) as u8 } else { dst[i] };
+                        assert_eq!(x[i], expected);
+                    }
+                }
+            }
+        }
+    }
+
+
+    #[test]
+    fn bulk_operations_match_model() {
+        let mut rng = XorShift(0x9E37_79B9_7F4A_7C15);
+        for _ in 0..64 {
+            let src       = rng.bytes(256);
+            let dst       = rng.bytes(256);
+            let src_bit   = (rng.next() % 64) as usize;
+            let dst_bit   = (rng.next() % 64) as usize;
+            let bit_count = (rng.next() % 1900) as usize;
+
+            let srcptr = unsafe { BitPtr::new_on_byte(src.as_ptr()).cast_order::<Lsb0>().bit_add(src_bit) };
+            let model  = |bytes : &[u8]| (0..(bytes.len() * 8)).map(|i| unsafe { BitPtr::new_on_byte(bytes.as_ptr()).cast_order::<Lsb0>().bit_add(i).read() }).collect::<Vec<_>>();
+            let src_m  = model(&src);
+            let dst_m  = model(&dst);
+
+            assert_eq!(unsafe { crate::count_ones(srcptr, bit_count) }, src_m[src_bit..(src_bit + bit_count)].iter().filter(|&&b| b).count());
+
+            let mut x = dst.clone();
+            let xptr  = unsafe { BitPtrMut::new_on_byte(x.as_mut_ptr()).cast_order::<Lsb0>().bit_add(dst_bit) };
+            unsafe { crate::fill(xptr, bit_count, true); }
+            let mut expected = dst_m.clone();
+            expected[dst_bit..(dst_bit + bit_count)].fill(true);
+            assert_eq!(model(&x), expected);
+
+            let mut x = dst.clone();
+            let xptr  = unsafe { BitPtrMut::new_on_byte(x.as_mut_ptr()).cast_order::<Lsb0>().bit_add(dst_bit) };
+            unsafe { crate::copy_nonoverlapping(srcptr, xptr, bit_count); }
+            let mut expected = dst_m.clone();
+            expected[dst_bit..(dst_bit + bit_count)].copy_from_slice(&src_m[src_bit..(src_bit + bit_count)]);
+            assert_eq!(model(&x), expected);
+
+            let mut x = dst.clone();
+            let xptr  = unsafe { BitPtrMut::new_on_byte(x.as_mut_ptr()).cast_order::<Lsb0>().bit_add(dst_bit) };
+            unsafe { crate::xor_assign(srcptr, xptr, bit_count); }
+            let mut expected = dst_m.clone();
+            for i in 0..bit_count { expected[dst_bit + i] ^= src_m[src_bit + i]; }
+            assert_eq!(model(&x), expected);
+
+            // Matching alignments take the vectorised paths.
+            let mut x = dst.clone();
+            let xptr  = unsafe { BitPtrMut::new_on_byte(x.as_mut_ptr()).cast_order::<Lsb0>().bit_add(src_bit) };
+            unsafe { crate::xor_assign(srcptr, xptr, bit_count); }
+            let mut expected = dst_m.clone();
+            for i in 0..bit_count { expected[src_bit + i] ^= src_m[src_bit + i]; }
+            assert_eq!(model(&x), expected);
+
+            let mut x = dst.clone();
+            let xptr  = unsafe { BitPtrMut::new_on_byte(x.as_mut_ptr()).cast_order::<Lsb0>().bit_add(src_bit) };
+            unsafe { crate::copy_nonoverlapping(srcptr, xptr, bit_count); }
+            let mut expected = dst_m.clone();
+            expected[src_bit..(src_bit + bit_count)].copy_from_slice(&src_m[src_bit..(src_bit + bit_count)]);
+            assert_eq!(model(&x), expected);
+        }
+    }
+
+}
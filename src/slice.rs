@@ -0,0 +1,450 @@
+use crate::{ BitPtr, BitPtrMut, BitOrder, Msb0 };
+use crate::iter::{ Iter, IterOnes, IterZeros };
+use core::marker::PhantomData;
+use core::ops::{ Bound, RangeBounds };
+
+
+/// A borrowed view of a range of bits.
+///
+/// Analagous to [`&[bool]`](prim@slice), but packed.
+///
+/// `BitSlice` is neither [`Send`] nor [`Sync`], as a [`BitSliceMut`] may be writing to other bits of the same bytes.
+#[derive(Clone, Copy)]
+pub struct BitSlice<'a, O : BitOrder = Msb0> {
+    ptr   : BitPtr<O>,
+    len   : usize,
+    bytes : PhantomData<&'a [u8]>
+}
+
+impl<'a> BitSlice<'a> {
+
+    /// Create a new bit slice over all bits of `bytes`.
+    ///
+    /// The new bit slice uses [`Msb0`] bit order. See [`cast_order`](Self::cast_order) to use another bit order.
+    #[inline]
+    pub fn new(bytes : &'a [u8]) -> Self {
+        unsafe { Self::from_raw_parts(BitPtr::new_on_byte(bytes.as_ptr()), bits_in(bytes.len())) }
+    }
+
+    /// Create a new bit slice over a range of bits of `bytes`.
+    ///
+    /// The new bit slice uses [`Msb0`] bit order. See [`cast_order`](Self::cast_order) to use another bit order.
+    ///
+    /// # Returns
+    /// Returns `None` if `range` is out of bounds, or its start is greater than its end.
+    #[inline]
+    pub fn new_range<R : RangeBounds<usize>>(bytes : &'a [u8], range : R) -> Option<Self> {
+        Self::new(bytes).get_range(range)
+    }
+
+}
+
+impl<'a, O : BitOrder> BitSlice<'a, O> {
+
+    /// Create a new bit slice from a raw bit pointer and a length in bits.
+    ///
+    /// # Safety
+    /// Behaviour is undefined if `ptr.floor_byte()` is not [valid](core::ptr#safety) for reads of `((ptr.subbyte_bit().get() as usize) + len).div_ceil(8)` bytes
+    ///  for the lifetime `'a`, or if the `len` bits at `ptr` are written to through anything other than [`Self`] during the lifetime `'a`.
+    ///
+    /// ---
+    /// Analagous to [`slice::from_raw_parts`](core::slice::from_raw_parts).
+    #[inline(always)]
+    pub const unsafe fn from_raw_parts(ptr : BitPtr<O>, len : usize) -> Self {
+        Self { ptr, len, bytes : PhantomData }
+    }
+
+    /// Returns the raw bit pointer to the first bit of this bit slice.
+    #[inline(always)]
+    pub const fn as_bitptr(&self) -> BitPtr<O> { self.ptr }
+
+    /// Returns the number of bits in this bit slice.
+    #[inline(always)]
+    pub const fn len(&self) -> usize { self.len }
+
+    /// Returns `true` if this bit slice has a length of `0`.
+    #[inline(always)]
+    pub const fn is_empty(&self) -> bool { self.len == 0 }
+
+    /// Changes the bit order of this bit slice, keeping the same bytes and bit range.
+    ///
+    /// See [`BitPtr::cast_order`].
+    #[inline(always)]
+    pub const fn cast_order<P : BitOrder>(self) -> BitSlice<'a, P> {
+        BitSlice { ptr : self.ptr.cast_order(), len : self.len, bytes : PhantomData }
+    }
+
+    /// Returns the bit at `index`, or `None` if `index` is out of bounds.
+    #[inline]
+    pub fn get(&self, index : usize) -> Option<bool> {
+        if (index >= self.len) { return None; }
+        Some(unsafe { self.ptr.bit_add(index).read() })
+    }
+
+    /// Returns a bit slice over a range of the bits of this bit slice.
+    ///
+    /// # Returns
+    /// Returns `None` if `range` is out of bounds, or its start is greater than its end.
+    #[inline]
+    pub fn get_range<R : RangeBounds<usize>>(&self, range : R) -> Option<BitSlice<'a, O>> {
+        let (start, end,) = resolve_range(range, self.len)?;
+        Some(unsafe { BitSlice::from_raw_parts(self.ptr.bit_add(start), end - start) })
+    }
+
+    /// Divides this bit slice into two at an index.
+    ///
+    /// The first will contain the bits in `[0, mid)`, and the second will contain the bits in `[mid, len)`.
+    ///
+    /// # Panics
+    /// Panics if `mid > len`.
+    ///
+    /// ---
+    /// Analagous to [`slice::split_at`](prim@slice#method.split_at).
+    #[inline]
+    pub fn split_at(self, mid : usize) -> (BitSlice<'a, O>, BitSlice<'a, O>,) {
+        assert!(mid <= self.len, "mid > len");
+        unsafe { (
+            BitSlice::from_raw_parts(self.ptr, mid),
+            BitSlice::from_raw_parts(self.ptr.bit_add(mid), self.len - mid),
+        ) }
+    }
+
+    /// Returns an iterator over the bits of this bit slice.
+    #[inline]
+    pub fn iter(&self) -> Iter<'a, O> { Iter::new(*self) }
+
+    /// Returns an iterator over the indices of the `1` bits of this bit slice.
+    ///
+    /// Creating the iterator counts the `1` bits, so that it can implement [`ExactSizeIterator`].
+    #[inline]
+    pub fn iter_ones(&self) -> IterOnes<'a, O> { IterOnes::new(*self) }
+
+    /// Returns an iterator over the indices of the `0` bits of this bit slice.
+    ///
+    /// Creating the iterator counts the `0` bits, so that it can implement [`ExactSizeIterator`].
+    #[inline]
+    pub fn iter_zeros(&self) -> IterZeros<'a, O> { IterZeros::new(*self) }
+
+}
+
+impl<'a, O : BitOrder> IntoIterator for BitSlice<'a, O> {
+    type Item     = bool;
+    type IntoIter = Iter<'a, O>;
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { self.iter() }
+}
+
+
+/// A mutably borrowed view of a range of bits.
+///
+/// Analagous to [`&mut [bool]`](prim@slice), but packed.
+///
+/// `BitSliceMut` is neither [`Send`] nor [`Sync`], as another `BitSliceMut` produced by [`split_at`](Self::split_at) may be writing to other bits of the same bytes.
+pub struct BitSliceMut<'a, O : BitOrder = Msb0> {
+    ptr   : BitPtrMut<O>,
+    len   : usize,
+    bytes : PhantomData<&'a mut [u8]>
+}
+
+impl<'a> BitSliceMut<'a> {
+
+    /// Create a new mutable bit slice over all bits of `bytes`.
+    ///
+    /// The new bit slice uses [`Msb0`] bit order. See [`cast_order`](Self::cast_order) to use another bit order.
+    #[inline]
+    pub fn new(bytes : &'a mut [u8]) -> Self {
+        unsafe { Self::from_raw_parts(BitPtrMut::new_on_byte(bytes.as_mut_ptr()), bits_in(bytes.len())) }
+    }
+
+    /// Create a new mutable bit slice over a range of bits of `bytes`.
+    ///
+    /// The new bit slice uses [`Msb0`] bit order. See [`cast_order`](Self::cast_order) to use another bit order.
+    ///
+    /// # Returns
+    /// Returns `None` if `range` is out of bounds, or its start is greater than its end.
+    #[inline]
+    pub fn new_range<R : RangeBounds<usize>>(bytes : &'a mut [u8], range : R) -> Option<Self> {
+        Self::new(bytes).into_range(range)
+    }
+
+}
+
+impl<'a, O : BitOrder> BitSliceMut<'a, O> {
+
+    /// Create a new mutable bit slice from a raw bit pointer and a length in bits.
+    ///
+    /// # Safety
+    /// Behaviour is undefined if `ptr.floor_byte()` is not [valid](core::ptr#safety) for reads and writes of `((ptr.subbyte_bit().get() as usize) + len).div_ceil(8)` bytes
+    ///  for the lifetime `'a`, or if the `len` bits at `ptr` are accessed through anything other than [`Self`] during the lifetime `'a`.
+    ///
+    /// ---
+    /// Analagous to [`slice::from_raw_parts_mut`](core::slice::from_raw_parts_mut).
+    #[inline(always)]
+    pub const unsafe fn from_raw_parts(ptr : BitPtrMut<O>, len : usize) -> Self {
+        Self { ptr, len, bytes : PhantomData }
+    }
+
+    /// Returns the raw bit pointer to the first bit of this bit slice.
+    #[inline(always)]
+    pub const fn as_bitptr(&self) -> BitPtr<O> { self.ptr.as_const() }
+
+    /// Returns the mutable raw bit pointer to the first bit of this bit slice.
+    #[inline(always)]
+    pub const fn as_bitptr_mut(&mut self) -> BitPtrMut<O> { self.ptr }
+
+    /// Returns the number of bits in this bit slice.
+    #[inline(always)]
+    pub const fn len(&self) -> usize { self.len }
+
+    /// Returns `true` if this bit slice has a length of `0`.
+    #[inline(always)]
+    pub const fn is_empty(&self) -> bool { self.len == 0 }
+
+    /// Changes the bit order of this bit slice, keeping the same bytes and bit range.
+    ///
+    /// See [`BitPtrMut::cast_order`].
+    #[inline(always)]
+    pub const fn cast_order<P : BitOrder>(self) -> BitSliceMut<'a, P> {
+        BitSliceMut { ptr : self.ptr.cast_order(), len : self.len, bytes : PhantomData }
+    }
+
+    /// Returns an immutable view of the bits of this bit slice.
+    #[inline(always)]
+    pub const fn as_bitslice(&self) -> BitSlice<'_, O> {
+        unsafe { BitSlice::from_raw_parts(self.ptr.as_const(), self.len) }
+    }
+
+    /// Returns a shorter-lived mutable view of the bits of this bit slice.
+    #[inline(always)]
+    pub const fn reborrow(&mut self) -> BitSliceMut<'_, O> {
+        unsafe { BitSliceMut::from_raw_parts(self.ptr, self.len) }
+    }
+
+    /// Returns the bit at `index`, or `None` if `index` is out of bounds.
+    #[inline]
+    pub fn get(&self, index : usize) -> Option<bool> {
+        self.as_bitslice().get(index)
+    }
+
+    /// Sets the bit at `index` to `value`.
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds.
+    #[inline]
+    pub fn set(&mut self, index : usize, value : bool) {
+        assert!(index < self.len, "index out of bounds");
+        unsafe { self.ptr.bit_add(index).write(value); }
+    }
+
+    /// Returns a mutable bit slice over a range of the bits of this bit slice.
+    ///
+    /// # Returns
+    /// Returns `None` if `range` is out of bounds, or its start is greater than its end.
+    #[inline]
+    pub fn get_range<R : RangeBounds<usize>>(&mut self, range : R) -> Option<BitSliceMut<'_, O>> {
+        self.reborrow().into_range(range)
+    }
+
+    /// Converts this bit slice into a bit slice over a range of its bits, keeping the same lifetime.
+    ///
+    /// # Returns
+    /// Returns `None` if `range` is out of bounds, or its start is greater than its end.
+    #[inline]
+    pub fn into_range<R : RangeBounds<usize>>(self, range : R) -> Option<BitSliceMut<'a, O>> {
+        let (start, end,) = resolve_range(range, self.len)?;
+        Some(unsafe { BitSliceMut::from_raw_parts(self.ptr.bit_add(start), end - start) })
+    }
+
+    /// Divides this bit slice into two at an index.
+    ///
+    /// The first will contain the bits in `[0, mid)`, and the second will contain the bits in `[mid, len)`.
+    ///
+    /// # Panics
+    /// Panics if `mid > len`.
+    ///
+    /// ---
+    /// Analagous to [`slice::split_at_mut`](prim@slice#method.split_at_mut).
+    #[inline]
+    pub fn split_at(self, mid : usize) -> (BitSliceMut<'a, O>, BitSliceMut<'a, O>,) {
+        assert!(mid <= self.len, "mid > len");
+        unsafe { (
+            BitSliceMut::from_raw_parts(self.ptr, mid),
+            BitSliceMut::from_raw_parts(self.ptr.bit_add(mid), self.len - mid),
+        ) }
+    }
+
+    /// Copies all bits from `src` into this bit slice.
+    ///
+    /// `src` may use a different [bit order](BitOrder).
+    ///
+    /// # Panics
+    /// Panics if the two bit slices have different lengths.
+    ///
+    /// ---
+    /// Analagous to [`slice::copy_from_slice`](prim@slice#method.copy_from_slice).
+    #[inline]
+    pub fn copy_from_bitslice<P : BitOrder>(&mut self, src : &BitSlice<'_, P>) {
+        assert_eq!(self.len, src.len, "source and destination bit slices have different lengths");
+        unsafe { crate::copy_nonoverlapping(src.ptr, self.ptr, self.len); }
+    }
+
+    /// Fills this bit slice with `value`.
+    ///
+    /// ---
+    /// Analagous to [`slice::fill`](prim@slice#method.fill).
+    #[inline]
+    pub fn fill(&mut self, value : bool) {
+        unsafe { crate::fill(self.ptr, self.len, value); }
+    }
+
+    /// Swaps all bits in this bit slice with those in `other`.
+    ///
+    /// `other` may use a different [bit order](BitOrder).
+    ///
+    /// # Panics
+    /// Panics if the two bit slices have different lengths.
+    ///
+    /// ---
+    /// Analagous to [`slice::swap_with_slice`](prim@slice#method.swap_with_slice).
+    #[inline]
+    pub fn swap_with_bitslice<P : BitOrder>(&mut self, other : &mut BitSliceMut<'_, P>) {
+        assert_eq!(self.len, other.len, "bit slices have different lengths");
+        unsafe { crate::swap_nonoverlapping(self.ptr, other.ptr, self.len); }
+    }
+
+}
+
+impl<'a, O : BitOrder> From<BitSliceMut<'a, O>> for BitSlice<'a, O> {
+    #[inline(always)]
+    fn from(value : BitSliceMut<'a, O>) -> Self {
+        unsafe { BitSlice::from_raw_parts(value.ptr.as_const(), value.len) }
+    }
+}
+
+
+/// Returns the number of bits in `byte_count` bytes.
+///
+/// # Panics
+/// Panics if the number of bits does not fit in a `usize`.
+#[inline]
+pub(crate) const fn bits_in(byte_count : usize) -> usize {
+    byte_count.checked_mul(8).expect("bit count overflowed usize")
+}
+
+
+/// Converts `range` into a start and end index, checking that it is in bounds of a range of `len` items.
+#[inline]
+pub(crate) fn resolve_range<R : RangeBounds<usize>>(range : R, len : usize) -> Option<(usize, usize,)> {
+    let start = match (range.start_bound()) {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start.checked_add(1)?,
+        Bound::Unbounded        => 0
+    };
+    let end = match (range.end_bound()) {
+        Bound::Included(&end) => end.checked_add(1)?,
+        Bound::Excluded(&end) => end,
+        Bound::Unbounded      => len
+    };
+    if (start > end || end > len) { return None; }
+    Some((start, end,))
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Lsb0;
+
+
+    #[test]
+    fn bitslice_get_range() {
+        let x = [0b01011011u8, 0b10010110];
+
+        let xs = BitSlice::new(&x);
+        assert_eq!(xs.len(), 16);
+        assert_eq!(xs.get(0), Some(false));
+        assert_eq!(xs.get(1), Some(true));
+        assert_eq!(xs.get(15), Some(false));
+        assert_eq!(xs.get(16), None);
+
+        let ys = BitSlice::new_range(&x, 6..11).unwrap();
+        assert_eq!(ys.len(), 5);
+        assert_eq!((0..5).map(|i| ys.get(i).unwrap()).collect::<Vec<_>>(), [true, true, true, false, false]);
+        assert!(ys.get_range(2..=4).is_some());
+        assert!(ys.get_range(2..=5).is_none());
+        assert!(BitSlice::new_range(&x, 6..17).is_none());
+        #[allow(clippy::reversed_empty_ranges)]
+        let reversed = BitSlice::new_range(&x, 7..6);
+        assert!(reversed.is_none());
+
+        let zs = BitSlice::new(&x).cast_order::<Lsb0>();
+        assert_eq!(zs.get(0), Some(true));
+        assert_eq!(zs.get(2), Some(false));
+    }
+
+
+    #[test]
+    fn bitslice_split_at() {
+        let x = [0b01011011u8, 0b10010110];
+
+        let (l, r,) = BitSlice::new_range(&x, 3..).unwrap().split_at(6);
+        assert_eq!(l.len(), 6);
+        assert_eq!(r.len(), 7);
+        assert_eq!(l.get(5), Some(true));
+        assert_eq!(r.get(0), Some(false));
+    }
+
+
+    #[test]
+    fn bitslicemut_set_fill() {
+        let mut x = [0b01011011u8, 0b10010110];
+
+        let mut xs = BitSliceMut::new_range(&mut x, 3..13).unwrap();
+        xs.set(0, false);
+        xs.set(8, false);
+        assert_eq!(x, [0b01001011u8, 0b10000110]);
+
+        let (mut l, mut r,) = BitSliceMut::new(&mut x).split_at(7);
+        l.fill(true);
+        r.fill(false);
+        assert_eq!(x, [0b11111110u8, 0b00000000]);
+    }
+
+
+    #[test]
+    #[should_panic]
+    fn bitslicemut_set_out_of_bounds() {
+        let mut x = [0u8; 2];
+        BitSliceMut::new_range(&mut x, 3..13).unwrap().set(10, true);
+    }
+
+
+    #[test]
+    fn bitslicemut_copy_swap() {
+        let     x = [0b01011011u8, 0b10010110];
+        let mut y = [0b11111111u8, 0b11111111];
+
+        let xs = BitSlice::new_range(&x, 7..14).unwrap();
+        let mut ys = BitSliceMut::new_range(&mut y, 3..10).unwrap();
+        ys.copy_from_bitslice(&xs);
+        assert_eq!(y, [0b11111001u8, 0b01111111]);
+
+        let mut a = [0b01011011u8, 0b10010110];
+        let mut b = [0b11101000u8, 0b11010010];
+        let mut xs = BitSliceMut::new_range(&mut a, 7..14).unwrap();
+        let mut ys = BitSliceMut::new_range(&mut b, 3..10).unwrap();
+        xs.swap_with_bitslice(&mut ys);
+        assert_eq!(a, [0b01011010u8, 0b10001110]);
+        assert_eq!(b, [0b11111001u8, 0b01010010]);
+    }
+
+
+    #[test]
+    #[should_panic]
+    fn bitslicemut_copy_different_lengths() {
+        let     x = [0u8; 2];
+        let mut y = [0u8; 2];
+        BitSliceMut::new(&mut y).copy_from_bitslice(&BitSlice::new_range(&x, 1..).unwrap());
+    }
+
+}
@@ -1,5 +1,6 @@
-use crate::BitPtrMut;
-use core::ptr;
+use crate::{ BitPtrMut, BitOrder };
+use crate::copy::read_in_bounds;
+use crate::order::reorder;
 
 
 /// Swaps `bit_count` bits between the two regions of memory beginning at `x` and `y`. The two regions must *not* overlap.
@@ -8,6 +9,8 @@ use core::ptr;
 ///
 /// The copy is "untyped" in the sense that data may be uninitialized. The initialization state is preserved exactly.
 ///
+/// `x` and `y` may use different [bit orders](BitOrder). Bit `n` of the region at `x` is swapped with bit `n` of the region at `y`.
+///
 ///
 /// # Safety
 ///
@@ -60,7 +63,7 @@ use core::ptr;
 ///
 /// ---
 /// Analagous to [`ptr::swap_nonoverlapping`](core::ptr::swap_nonoverlapping).
-pub unsafe fn swap_nonoverlapping(x : BitPtrMut, y : BitPtrMut, bit_count : usize) {
+pub unsafe fn swap_nonoverlapping<X : BitOrder, Y : BitOrder>(x : BitPtrMut<X>, y : BitPtrMut<Y>, bit_count : usize) {
     if (bit_count == 0) { return; }
 
     let (x_byte, x_bit,) = x.as_inner();
@@ -70,14 +73,28 @@ pub unsafe fn swap_nonoverlapping(x : BitPtrMut, y : BitPtrMut, bit_count : usiz
     let y_bit_l = y_bit.get() as usize;
     let y_bit_r = (8isize - ((y_bit_l + bit_count) as isize)).rem_euclid(8);
 
-    let mut x_rolling = u32::from_be(unsafe { ptr::read(x_byte.byte_sub(2) as *const u32) });
-    let mut y_rolling = u32::from_be(unsafe { ptr::read(y_byte.byte_sub(2) as *const u32) });
-
     let x_byte_count = (x_bit_l + bit_count).div_ceil(8);
     let y_byte_count = (y_bit_l + bit_count).div_ceil(8);
+
+    // Find the source bytes that line up with the first destination bytes, and the bit shifts between them.
+    let x_shift = (x_bit_l as isize) - (y_bit_l as isize);
+    let x_first = x_shift.div_euclid(8);
+    let x_shift = x_shift.rem_euclid(8) as u32;
+    let y_shift = (y_bit_l as isize) - (x_bit_l as isize);
+    let y_first = y_shift.div_euclid(8);
+    let y_shift = y_shift.rem_euclid(8) as u32;
+
+    let mut x_rolling = reorder::<X>(unsafe { read_in_bounds(x_byte, x_byte_count, x_first) }) as u16;
+    let mut y_rolling = reorder::<Y>(unsafe { read_in_bounds(y_byte, y_byte_count, y_first) }) as u16;
+
     for offset in 0..(x_byte_count.max(y_byte_count)) {
-        let x_byte = unsafe { x_byte.byte_add(offset) };
-        let y_byte = unsafe { y_byte.byte_add(offset) };
+
+        // Roll the source values.
+        x_rolling = (x_rolling << 8) | (reorder::<X>(unsafe { read_in_bounds(x_byte, x_byte_count, x_first + (offset as isize) + 1) }) as u16);
+        y_rolling = (y_rolling << 8) | (reorder::<Y>(unsafe { read_in_bounds(y_byte, y_byte_count, y_first + (offset as isize) + 1) }) as u16);
+
+        let x_byte = x_byte.wrapping_byte_add(offset);
+        let y_byte = y_byte.wrapping_byte_add(offset);
 
         // Get masks over the bits to write.
         let mut x_mask = u8::MAX;
@@ -97,29 +114,20 @@ pub unsafe fn swap_nonoverlapping(x : BitPtrMut, y : BitPtrMut, bit_count : usiz
             _ => { y_mask = 0b00000000; }
         }
 
-        // Build the bytes that will be written.
-        let x_src_b = ((y_rolling << y_bit_l >> (8 + x_bit_l)) & 0b11111111) as u8;
-        let y_src_b = ((x_rolling << x_bit_l >> (8 + y_bit_l)) & 0b11111111) as u8;
-
-        // Get the bytes to edit.
-        let mut x_dst_b = unsafe { *x_byte };
-        let mut y_dst_b = unsafe { *y_byte };
-
-        // Wipe the bits that will be overwritten.
-        x_dst_b &= ! x_mask;
-        y_dst_b &= ! y_mask;
-
-        // Write the relevant bits.
-        x_dst_b |= x_src_b & x_mask;
-        y_dst_b |= y_src_b & y_mask;
+        let x_mask = reorder::<X>(x_mask);
+        let y_mask = reorder::<Y>(y_mask);
 
-        // Overwrite the bytes.
-        unsafe { *x_byte = x_dst_b; }
-        unsafe { *y_byte = y_dst_b; }
+        // Build the bytes that will be written.
+        let x_src_b = reorder::<X>((y_rolling << y_shift >> 8) as u8);
+        let y_src_b = reorder::<Y>((x_rolling << x_shift >> 8) as u8);
 
-        // Roll the source values.
-        x_rolling = (x_rolling << 8) | ((unsafe { x_byte.byte_add(1).read() }) as u32);
-        y_rolling = (y_rolling << 8) | ((unsafe { y_byte.byte_add(1).read() }) as u32);
+        // Overwrite the relevant bits of the bytes.
+        if (x_mask != 0) {
+            unsafe { *x_byte = (*x_byte & ! x_mask) | (x_src_b & x_mask); }
+        }
+        if (y_mask != 0) {
+            unsafe { *y_byte = (*y_byte & ! y_mask) | (y_src_b & y_mask); }
+        }
 
     }
 }
@@ -128,6 +136,7 @@ pub unsafe fn swap_nonoverlapping(x : BitPtrMut, y : BitPtrMut, bit_count : usiz
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{ Lsb0, SubByte };
 
 
     #[test]
@@ -205,4 +214,51 @@ mod tests {
     }
 
 
+
+    #[test]
+    fn swap_buffer_edges() {
+        // Every region is exactly as large as the bytes the swap is allowed to touch.
+        let x = [0b01011011u8, 0b10010110, 0b11100001];
+        let y = [0b11101000u8, 0b11010010, 0b00111010];
+        let bit_at = |bytes : &[u8], i : usize| (bytes[i / 8] << (i % 8)) & 0b10000000 != 0;
+        for x_bit in 0..8usize {
+            for y_bit in 0..8usize {
+                for bit_count in 0..=(24 - x_bit.max(y_bit)) {
+                    let x_len = (x_bit + bit_count).div_ceil(8);
+                    let y_len = (y_bit + bit_count).div_ceil(8);
+                    let mut xs = x[..x_len].to_vec();
+                    let mut ys = y[..y_len].to_vec();
+
+                    let xptr = BitPtrMut::new(xs.as_mut_ptr(), SubByte::new(x_bit as u8).unwrap());
+                    let yptr = BitPtrMut::new(ys.as_mut_ptr(), SubByte::new(y_bit as u8).unwrap());
+                    unsafe { swap_nonoverlapping(xptr, yptr, bit_count); }
+
+                    for i in 0..(x_len * 8) {
+                        let expected = if (i >= x_bit && i < x_bit + bit_count) { bit_at(&y, i - x_bit + y_bit) } else { bit_at(&x, i) };
+                        assert_eq!(bit_at(&xs, i), expected);
+                    }
+                    for i in 0..(y_len * 8) {
+                        let expected = if (i >= y_bit && i < y_bit + bit_count) { bit_at(&x, i - y_bit + x_bit) } else { bit_at(&y, i) };
+                        assert_eq!(bit_at(&ys, i), expected);
+                    }
+                }
+            }
+        }
+    }
+
+
+
+    #[test]
+    fn swap_between_bit_orders() {
+        let mut x = 0b0101101110010110u16.to_be();
+        let mut y = 0b1110100011010010u16.to_le();
+
+        let xptr = unsafe { BitPtrMut::new_with_offset(&mut x as *mut _ as *mut _, 7) };
+        let yptr = unsafe { BitPtrMut::new_on_byte(&mut y as *mut _ as *mut _).cast_order::<Lsb0>().bit_offset(3) };
+
+        // Swap and check final values.
+        unsafe { swap_nonoverlapping(xptr, yptr, 7); }
+        assert_eq!(u16::from_be(x), 0b0101101010110010u16);
+        assert_eq!(u16::from_le(y), 0b1110101010011010u16);
+    }
 }
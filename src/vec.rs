@@ -0,0 +1,425 @@
+use crate::{ BitPtr, BitPtrMut, BitOrder, Msb0, BitSlice, BitSliceMut };
+use crate::slice::bits_in;
+use core::marker::PhantomData;
+use alloc::vec::Vec;
+use alloc::boxed::Box;
+
+
+/// A contiguous growable array of bits.
+///
+/// Analagous to [`Vec<bool>`](alloc::vec::Vec), but packed.
+///
+/// Bits past the end of a `BitVec` in its last byte are always `0`.
+#[derive(Clone, PartialEq, Eq, Hash, Default)]
+pub struct BitVec<O : BitOrder = Msb0> {
+    bytes : Vec<u8>,
+    len   : usize,
+    order : PhantomData<O>
+}
+
+impl BitVec {
+
+    /// Create a new, empty `BitVec`.
+    ///
+    /// The new `BitVec` uses [`Msb0`] bit order. See [`cast_order`](Self::cast_order) to use another bit order.
+    ///
+    /// ---
+    /// Analagous to [`Vec::new`].
+    #[inline]
+    pub const fn new() -> Self {
+        Self { bytes : Vec::new(), len : 0, order : PhantomData }
+    }
+
+    /// Create a new, empty `BitVec` with at least the specified capacity in bits.
+    ///
+    /// The new `BitVec` uses [`Msb0`] bit order. See [`cast_order`](Self::cast_order) to use another bit order.
+    ///
+    /// ---
+    /// Analagous to [`Vec::with_capacity`].
+    #[inline]
+    pub fn with_capacity(bit_capacity : usize) -> Self {
+        Self { bytes : Vec::with_capacity(bit_capacity.div_ceil(8)), len : 0, order : PhantomData }
+    }
+
+}
+
+impl<O : BitOrder> BitVec<O> {
+
+    /// Changes the bit order of this `BitVec`, keeping the same bytes and length.
+    ///
+    /// The bits past the end of this `BitVec` in its last byte are cleared again under the new bit order.
+    ///
+    /// See [`BitPtr::cast_order`].
+    #[inline]
+    pub fn cast_order<P : BitOrder>(self) -> BitVec<P> {
+        let mut bitvec = BitVec { bytes : self.bytes, len : self.len, order : PhantomData };
+        bitvec.clear_tail();
+        bitvec
+    }
+
+    /// Returns the number of bits in this `BitVec`.
+    #[inline(always)]
+    pub const fn len(&self) -> usize { self.len }
+
+    /// Returns `true` if this `BitVec` contains no bits.
+    #[inline(always)]
+    pub const fn is_empty(&self) -> bool { self.len == 0 }
+
+    /// Returns the number of bits this `BitVec` can hold without reallocating.
+    #[inline]
+    pub fn capacity(&self) -> usize { bits_in(self.bytes.capacity()) }
+
+    /// Returns the raw bit pointer to the first bit of this `BitVec`.
+    #[inline]
+    pub fn as_bitptr(&self) -> BitPtr<O> {
+        BitPtr::new_on_byte(self.bytes.as_ptr()).cast_order()
+    }
+
+    /// Returns the mutable raw bit pointer to the first bit of this `BitVec`.
+    #[inline]
+    pub fn as_bitptr_mut(&mut self) -> BitPtrMut<O> {
+        BitPtrMut::new_on_byte(self.bytes.as_mut_ptr()).cast_order()
+    }
+
+    /// Returns a bit slice over all bits of this `BitVec`.
+    #[inline]
+    pub fn as_bitslice(&self) -> BitSlice<'_, O> {
+        unsafe { BitSlice::from_raw_parts(self.as_bitptr(), self.len) }
+    }
+
+    /// Returns a mutable bit slice over all bits of this `BitVec`.
+    #[inline]
+    pub fn as_mut_bitslice(&mut self) -> BitSliceMut<'_, O> {
+        unsafe { BitSliceMut::from_raw_parts(self.as_bitptr_mut(), self.len) }
+    }
+
+    /// Returns the underlying bytes of this `BitVec`.
+    #[inline(always)]
+    pub fn as_bytes(&self) -> &[u8] { &self.bytes }
+
+    /// Returns the bit at `index`, or `None` if `index` is out of bounds.
+    #[inline]
+    pub fn get(&self, index : usize) -> Option<bool> {
+        self.as_bitslice().get(index)
+    }
+
+    /// Sets the bit at `index` to `value`.
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds.
+    #[inline]
+    pub fn set(&mut self, index : usize, value : bool) {
+        self.as_mut_bitslice().set(index, value);
+    }
+
+    /// Reserves capacity for at least `additional` more bits.
+    ///
+    /// ---
+    /// Analagous to [`Vec::reserve`].
+    #[inline]
+    pub fn reserve(&mut self, additional : usize) {
+        let new_len = self.len.checked_add(additional).expect("capacity overflow");
+        self.bytes.reserve(new_len.div_ceil(8) - self.bytes.len());
+    }
+
+    /// Appends a bit to the back of this `BitVec`.
+    ///
+    /// ---
+    /// Analagous to [`Vec::push`].
+    #[inline]
+    pub fn push(&mut self, value : bool) {
+        if (self.len.is_multiple_of(8)) {
+            self.bytes.push(0);
+        }
+        let index = self.len;
+        self.len += 1;
+        self.set(index, value);
+    }
+
+    /// Removes the last bit from this `BitVec` and returns it, or `None` if it is empty.
+    ///
+    /// ---
+    /// Analagous to [`Vec::pop`].
+    #[inline]
+    pub fn pop(&mut self) -> Option<bool> {
+        let value = self.get(self.len.checked_sub(1)?)?;
+        self.truncate(self.len - 1);
+        Some(value)
+    }
+
+    /// Appends all bits of `other` to the back of this `BitVec`.
+    ///
+    /// `other` may use a different [bit order](BitOrder).
+    ///
+    /// ---
+    /// Analagous to [`Vec::extend_from_slice`].
+    pub fn extend_from_bitslice<P : BitOrder>(&mut self, other : &BitSlice<'_, P>) {
+        let start = self.len;
+        self.grow(other.len());
+        unsafe { crate::copy_nonoverlapping(other.as_bitptr(), self.as_bitptr_mut().bit_add(start), other.len()); }
+    }
+
+    /// Inserts a bit at `index`, shifting all bits after it towards the back.
+    ///
+    /// # Panics
+    /// Panics if `index > len`.
+    ///
+    /// ---
+    /// Analagous to [`Vec::insert`].
+    pub fn insert(&mut self, index : usize, value : bool) {
+        assert!(index <= self.len, "insertion index (is {index}) should be <= len (is {})", self.len);
+        self.grow(1);
+        unsafe { crate::shift_right(self.as_bitptr_mut().bit_add(index), self.len - index, 1, value); }
+    }
+
+    /// Removes and returns the bit at `index`, shifting all bits after it towards the front.
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds.
+    ///
+    /// ---
+    /// Analagous to [`Vec::remove`].
+    pub fn remove(&mut self, index : usize) -> bool {
+        assert!(index < self.len, "removal index (is {index}) should be < len (is {})", self.len);
+        let ptr   = unsafe { self.as_bitptr_mut().bit_add(index) };
+        let value = unsafe { ptr.as_const().read() };
+        unsafe { crate::shift_left(ptr, self.len - index, 1, false); }
+        self.truncate(self.len - 1);
+        value
+    }
+
+    /// Shortens this `BitVec` to `len` bits, dropping the rest.
+    ///
+    /// If `len` is greater than or equal to the current length, this has no effect.
+    ///
+    /// ---
+    /// Analagous to [`Vec::truncate`].
+    pub fn truncate(&mut self, len : usize) {
+        if (len >= self.len) { return; }
+        self.len = len;
+        self.bytes.truncate(len.div_ceil(8));
+        self.clear_tail();
+    }
+
+    /// Resizes this `BitVec` in-place so that its length is equal to `new_len`.
+    ///
+    /// If `new_len` is greater than the current length, the new bits are set to `value`.
+    ///
+    /// ---
+    /// Analagous to [`Vec::resize`].
+    pub fn resize(&mut self, new_len : usize, value : bool) {
+        if (new_len <= self.len) {
+            self.truncate(new_len);
+        } else {
+            let start = self.len;
+            self.grow(new_len - start);
+            if (value) {
+                unsafe { crate::fill(self.as_bitptr_mut().bit_add(start), new_len - start, true); }
+            }
+        }
+    }
+
+    /// Removes all bits from this `BitVec`.
+    ///
+    /// ---
+    /// Analagous to [`Vec::clear`].
+    #[inline]
+    pub fn clear(&mut self) {
+        self.bytes.clear();
+        self.len = 0;
+    }
+
+    /// Converts this `BitVec` into a [`BitBox`], dropping any excess capacity.
+    ///
+    /// ---
+    /// Analagous to [`Vec::into_boxed_slice`].
+    #[inline]
+    pub fn into_boxed_bitslice(self) -> BitBox<O> {
+        BitBox { bytes : self.bytes.into_boxed_slice(), len : self.len, order : PhantomData }
+    }
+
+    /// Appends `additional` `0` bits to the back of this `BitVec`.
+    fn grow(&mut self, additional : usize) {
+        let new_len = self.len.checked_add(additional).expect("capacity overflow");
+        self.bytes.resize(new_len.div_ceil(8), 0);
+        self.len = new_len;
+    }
+
+    /// Sets the bits past the end of this `BitVec` in its last byte to `0`.
+    fn clear_tail(&mut self) {
+        let tail = bits_in(self.bytes.len()) - self.len;
+        let ptr  = unsafe { self.as_bitptr_mut().bit_add(self.len) };
+        unsafe { crate::fill(ptr, tail, false); }
+    }
+
+}
+
+impl<O : BitOrder> From<BitBox<O>> for BitVec<O> {
+    #[inline]
+    fn from(value : BitBox<O>) -> Self { value.into_bitvec() }
+}
+
+
+/// A fixed-size owned array of bits.
+///
+/// Analagous to [`Box<[bool]>`](alloc::boxed::Box), but packed.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct BitBox<O : BitOrder = Msb0> {
+    bytes : Box<[u8]>,
+    len   : usize,
+    order : PhantomData<O>
+}
+
+impl<O : BitOrder> BitBox<O> {
+
+    /// Returns the number of bits in this `BitBox`.
+    #[inline(always)]
+    pub const fn len(&self) -> usize { self.len }
+
+    /// Returns `true` if this `BitBox` contains no bits.
+    #[inline(always)]
+    pub const fn is_empty(&self) -> bool { self.len == 0 }
+
+    /// Returns a bit slice over all bits of this `BitBox`.
+    #[inline]
+    pub fn as_bitslice(&self) -> BitSlice<'_, O> {
+        unsafe { BitSlice::from_raw_parts(BitPtr::new_on_byte(self.bytes.as_ptr()).cast_order(), self.len) }
+    }
+
+    /// Returns a mutable bit slice over all bits of this `BitBox`.
+    #[inline]
+    pub fn as_mut_bitslice(&mut self) -> BitSliceMut<'_, O> {
+        unsafe { BitSliceMut::from_raw_parts(BitPtrMut::new_on_byte(self.bytes.as_mut_ptr()).cast_order(), self.len) }
+    }
+
+    /// Converts this `BitBox` into a [`BitVec`] without reallocating.
+    ///
+    /// ---
+    /// Analagous to [`<[T]>::into_vec`](prim@slice#method.into_vec).
+    #[inline]
+    pub fn into_bitvec(self) -> BitVec<O> {
+        BitVec { bytes : self.bytes.into_vec(), len : self.len, order : PhantomData }
+    }
+
+}
+
+impl<O : BitOrder> From<BitVec<O>> for BitBox<O> {
+    #[inline]
+    fn from(value : BitVec<O>) -> Self { value.into_boxed_bitslice() }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Lsb0;
+
+
+    fn bits<O : BitOrder>(bitvec : &BitVec<O>) -> Vec<bool> {
+        (0..bitvec.len()).map(|i| bitvec.get(i).unwrap()).collect()
+    }
+
+
+    #[test]
+    fn bitvec_push_pop() {
+        let mut x = BitVec::new();
+        let mut model = Vec::new();
+        for i in 0..37 {
+            let value = (i * 7) % 3 == 0;
+            x.push(value);
+            model.push(value);
+        }
+        assert_eq!(x.len(), 37);
+        assert_eq!(x.as_bytes().len(), 5);
+        assert_eq!(bits(&x), model);
+
+        for _ in 0..30 {
+            assert_eq!(x.pop(), model.pop());
+        }
+        assert_eq!(bits(&x), model);
+        assert_eq!(x.as_bytes(), [0b10010010u8]);
+
+        while (x.pop().is_some()) { }
+        assert!(x.is_empty());
+        assert_eq!(x.pop(), None);
+    }
+
+
+    #[test]
+    fn bitvec_cast_order_clears_tail() {
+        // The byte is kept, so the pushed bit moves to the other end of it, past the end of the bit vector.
+        let mut x = BitVec::new();
+        x.push(true);
+        let mut x = x.cast_order::<Lsb0>();
+        assert_eq!(bits(&x), [false]);
+        assert_eq!(x.as_bytes(), [0]);
+
+        let mut y = BitVec::new().cast_order::<Lsb0>();
+        y.push(false);
+        assert!(x == y);
+
+        for _ in 0..7 {
+            x.push(false);
+        }
+        assert_eq!(bits(&x), [false; 8]);
+    }
+
+
+    #[test]
+    fn bitvec_insert_remove() {
+        let mut x = BitVec::new().cast_order::<Lsb0>();
+        let mut model = Vec::new();
+        for i in 0..40 {
+            let index = (i * 13) % (model.len() + 1);
+            x.insert(index, i % 3 == 0);
+            model.insert(index, i % 3 == 0);
+            assert_eq!(bits(&x), model);
+        }
+        for i in 0..25 {
+            let index = (i * 11) % model.len();
+            assert_eq!(x.remove(index), model.remove(index));
+            assert_eq!(bits(&x), model);
+        }
+    }
+
+
+    #[test]
+    fn bitvec_extend_truncate_resize() {
+        let y = [0b01011011u8, 0b10010110];
+
+        let mut x = BitVec::with_capacity(3);
+        x.push(true);
+        x.extend_from_bitslice(&BitSlice::new_range(&y, 3..14).unwrap());
+        assert_eq!(x.as_bytes(), [0b11101110u8, 0b01010000]);
+
+        x.truncate(5);
+        assert_eq!(x.as_bytes(), [0b11101000u8]);
+
+        x.resize(11, true);
+        assert_eq!(x.as_bytes(), [0b11101111u8, 0b11100000]);
+        x.resize(13, false);
+        assert_eq!(x.as_bytes(), [0b11101111u8, 0b11100000]);
+        assert_eq!(x.len(), 13);
+
+        // Bits past the end are ignored when comparing.
+        let mut z = BitVec::new();
+        z.extend_from_bitslice(&BitSlice::new(&[0b11101111u8, 0b11100111]).get_range(..13).unwrap());
+        assert!(x == z);
+    }
+
+
+    #[test]
+    fn bitvec_reserve_boxed() {
+        let mut x = BitVec::new();
+        x.reserve(20);
+        assert!(x.capacity() >= 20);
+        x.resize(20, true);
+
+        let mut b = x.into_boxed_bitslice();
+        assert_eq!(b.len(), 20);
+        b.as_mut_bitslice().set(3, false);
+        let x = b.into_bitvec();
+        assert_eq!(x.as_bytes(), [0b11101111u8, 0b11111111, 0b11110000]);
+    }
+
+}
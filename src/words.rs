@@ -0,0 +1,175 @@
+use crate::{ BitPtr, BitOrder };
+use crate::order::{ reorder, reorder_u64 };
+use crate::copy::read_in_bounds;
+use core::marker::PhantomData;
+
+
+/// Reads a region of bits 64 bits at a time, regardless of its alignment.
+///
+/// Each word holds the next 64 bits of the region in [`Msb0`](crate::Msb0) order, so the first bit of the region is the most significant bit of the first word.
+///  The bits of the last word past the end of the region are `0`.
+///
+/// Words are read forwards through a rolling window. Words read from the back are loaded on their own.
+pub(crate) struct Words<O : BitOrder> {
+    byte       : *const u8,
+    byte_count : usize,
+    bit_count  : usize,
+    shift      : u32,
+    /// The byte offset of the next word to load into the rolling window.
+    next       : usize,
+    /// The two words that the next yielded word is built from.
+    rolling    : u128,
+    /// The index of the next word to yield from the front.
+    front      : usize,
+    /// The index after the next word to yield from the back.
+    back       : usize,
+    order      : PhantomData<O>
+}
+
+impl<O : BitOrder> Words<O> {
+
+    /// # Safety
+    /// Behaviour is undefined if `ptr.floor_byte()` is not [valid](core::ptr#safety) for reads of `((ptr.subbyte_bit().get() as usize) + bit_count).div_ceil(8)` bytes
+    ///  for as long as the returned value is used.
+    #[inline]
+    pub(crate) unsafe fn new(ptr : BitPtr<O>, bit_count : usize) -> Self {
+        let (byte, bit,) = ptr.as_inner();
+        let mut words = Self {
+            byte,
+            byte_count : ((bit.get() as usize) + bit_count).div_ceil(8),
+            bit_count,
+            shift      : bit.get() as u32,
+            next       : 16,
+            rolling    : 0,
+            front      : 0,
+            back       : bit_count.div_ceil(64),
+            order      : PhantomData
+        };
+        words.rolling = ((unsafe { words.load(0) } as u128) << 64) | (unsafe { words.load(8) } as u128);
+        words
+    }
+
+    /// Returns a mask over the bits of the word at `index` which are part of the region.
+    #[inline(always)]
+    fn mask(&self, index : usize) -> u64 {
+        word_mask(self.bit_count, index)
+    }
+
+    /// Loads the 8 bytes at `offset`, in [`Msb0`](crate::Msb0) order. Bytes outside of the region are `0`.
+    #[inline(always)]
+    unsafe fn load(&self, offset : usize) -> u64 {
+        if (offset + 8 <= self.byte_count) {
+            reorder_u64::<O>(u64::from_be(unsafe { self.byte.add(offset).cast::<u64>().read_unaligned() }))
+        } else {
+            let mut word = 0;
+            for i in 0..8 {
+                word = (word << 8) | (reorder::<O>(unsafe { read_in_bounds(self.byte, self.byte_count, (offset + i) as isize) }) as u64);
+            }
+            word
+        }
+    }
+
+}
+
+/// Returns a mask over the bits of the word at `index`, of a region of `bit_count` bits read by [`Words`], which are part of the region.
+#[inline(always)]
+pub(crate) fn word_mask(bit_count : usize, index : usize) -> u64 {
+    u64::MAX << (64 - (bit_count - (index * 64)).min(64))
+}
+
+impl<O : BitOrder> Iterator for Words<O> {
+    type Item = u64;
+
+    #[inline]
+    fn next(&mut self) -> Option<u64> {
+        if (self.front >= self.back) { return None; }
+        let word = ((self.rolling << self.shift) >> 64) as u64 & self.mask(self.front);
+        self.front += 1;
+        if (self.front < self.back) {
+            self.rolling = (self.rolling << 64) | (unsafe { self.load(self.next) } as u128);
+            self.next   += 8;
+        }
+        Some(word)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>,) {
+        let len = self.back - self.front;
+        (len, Some(len),)
+    }
+
+}
+
+impl<O : BitOrder> DoubleEndedIterator for Words<O> {
+
+    #[inline]
+    fn next_back(&mut self) -> Option<u64> {
+        if (self.front >= self.back) { return None; }
+        self.back -= 1;
+        let offset  = self.back * 8;
+        let rolling = ((unsafe { self.load(offset) } as u128) << 64) | (unsafe { self.load(offset + 8) } as u128);
+        Some(((rolling << self.shift) >> 64) as u64 & self.mask(self.back))
+    }
+
+}
+
+impl<O : BitOrder> ExactSizeIterator for Words<O> { }
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Lsb0;
+
+
+    #[test]
+    fn words_unaligned() {
+        let x = [0x01u8, 0x23, 0x45, 0x67, 0x89, 0xAB, 0xCD, 0xEF, 0xFE, 0xDC, 0xBA, 0x98, 0x76, 0x54, 0x32, 0x10, 0xFF, 0x00];
+        let value = u128::from_be_bytes(x[..16].try_into().unwrap());
+
+        let xptr = BitPtr::new_on_byte(x.as_ptr());
+        for start in 0..16 {
+            for bit_count in 0..=(x.len() * 8 - start) {
+                let words = unsafe { Words::new(xptr.bit_add(start), bit_count) }.collect::<Vec<_>>();
+                assert_eq!(words.len(), bit_count.div_ceil(64));
+                for (i, word,) in words.into_iter().enumerate() {
+                    let bits = (bit_count - (i * 64)).min(64);
+                    let expected = unsafe { xptr.bit_add(start + (i * 64)).read_bits::<u64>(bits as u32) } << (64 - bits);
+                    assert_eq!(word, expected);
+                }
+            }
+        }
+        assert_eq!(unsafe { Words::new(xptr.bit_add(4), 64) }.next(), Some((value << 4 >> 64) as u64));
+
+        let words = unsafe { Words::new(xptr.cast_order::<Lsb0>().bit_add(4), 12) }.collect::<Vec<_>>();
+        assert_eq!(words, [0b0000_1100_0100 << 52]);
+    }
+
+
+    #[test]
+    fn words_double_ended() {
+        let x = [0x01u8, 0x23, 0x45, 0x67, 0x89, 0xAB, 0xCD, 0xEF, 0xFE, 0xDC, 0xBA, 0x98, 0x76, 0x54, 0x32, 0x10, 0xFF, 0x00, 0x5A, 0xA5];
+
+        let xptr = BitPtr::new_on_byte(x.as_ptr());
+        for start in 0..8 {
+            for bit_count in 0..=(x.len() * 8 - start) {
+                let forwards = unsafe { Words::new(xptr.bit_add(start), bit_count) }.collect::<Vec<_>>();
+                let mut backwards = unsafe { Words::new(xptr.bit_add(start), bit_count) }.rev().collect::<Vec<_>>();
+                backwards.reverse();
+                assert_eq!(forwards, backwards);
+
+                // Alternating between the ends meets in the middle.
+                let mut words = unsafe { Words::new(xptr.bit_add(start), bit_count) };
+                let mut front = Vec::new();
+                let mut back  = Vec::new();
+                while let Some(word) = words.next() {
+                    front.push(word);
+                    if let Some(word) = words.next_back() { back.push(word); }
+                }
+                front.extend(back.into_iter().rev());
+                assert_eq!(front, forwards);
+            }
+        }
+    }
+
+}
@@ -0,0 +1,294 @@
+use crate::{ BitPtrMut, BitOrder, Msb0, BitSlice, BitSliceMut, Unsigned, Signed };
+#[cfg(feature = "alloc")]
+use crate::BitVec;
+use core::marker::PhantomData;
+use core::fmt;
+
+
+/// A cursor which writes bits to the front of a bit slice, or to the back of a [`BitVec`].
+///
+/// When writing to a bit slice, writing past the end returns a [`BufferFull`] error, and writes nothing.
+///
+/// ```rust
+/// use bitptr::BitWriter;
+///
+/// let mut x = [0u8; 2];
+///
+/// let mut writer = BitWriter::new(&mut x);
+/// writer.write_bool(true).unwrap();
+/// writer.write_bits::<u8>(0b_011, 3).unwrap();
+/// writer.write_bits_signed::<i8>(-0b_10101, 6).unwrap();
+/// writer.pad_to_byte(true).unwrap();
+/// assert_eq!(writer.bits_written(), 16);
+/// assert!(writer.write_bool(true).is_err());
+/// assert_eq!(x, [0b_10111010_u8, 0b_11111111_u8]);
+/// ```
+pub struct BitWriter<'a, O : BitOrder = Msb0> {
+    target  : Target<'a, O>,
+    written : usize
+}
+
+enum Target<'a, O : BitOrder> {
+    Buffer {
+        ptr      : BitPtrMut<O>,
+        capacity : usize,
+        bytes    : PhantomData<&'a mut [u8]>
+    },
+    #[cfg(feature = "alloc")]
+    BitVec(&'a mut BitVec<O>)
+}
+
+impl<'a> BitWriter<'a> {
+
+    /// Create a new writer over all bits of `bytes`.
+    ///
+    /// The new writer uses [`Msb0`] bit order. See [`cast_order`](Self::cast_order) to use another bit order.
+    #[inline]
+    pub fn new(bytes : &'a mut [u8]) -> Self {
+        Self::from_bitslice(BitSliceMut::new(bytes))
+    }
+
+}
+
+impl<'a, O : BitOrder> BitWriter<'a, O> {
+
+    /// Create a new writer over all bits of `bits`.
+    #[inline]
+    pub fn from_bitslice(mut bits : BitSliceMut<'a, O>) -> Self {
+        Self {
+            target  : Target::Buffer { ptr : bits.as_bitptr_mut(), capacity : bits.len(), bytes : PhantomData },
+            written : 0
+        }
+    }
+
+    /// Create a new writer which appends to the back of `bitvec`, growing it as needed.
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn from_bitvec(bitvec : &'a mut BitVec<O>) -> Self {
+        Self { target : Target::BitVec(bitvec), written : 0 }
+    }
+
+    /// Changes the bit order of this writer, keeping the same position.
+    ///
+    /// This is only possible for writers created from a bit slice.
+    ///
+    /// See [`BitPtrMut::cast_order`].
+    ///
+    /// # Returns
+    /// Returns `Err(self)` if this writer was created by [`from_bitvec`](Self::from_bitvec).
+    #[inline]
+    pub fn cast_order<P : BitOrder>(self) -> Result<BitWriter<'a, P>, Self> {
+        match (self.target) {
+            Target::Buffer { ptr, capacity, bytes } => Ok(BitWriter {
+                target  : Target::Buffer { ptr : ptr.cast_order(), capacity, bytes },
+                written : self.written
+            }),
+            #[cfg(feature = "alloc")]
+            Target::BitVec(_) => Err(self)
+        }
+    }
+
+    /// Returns the number of bits that have been written.
+    #[inline(always)]
+    pub const fn bits_written(&self) -> usize { self.written }
+
+    /// Returns the number of bits that can still be written, or `None` if the writer grows a [`BitVec`] as needed.
+    #[inline]
+    pub const fn remaining(&self) -> Option<usize> {
+        match (&self.target) {
+            Target::Buffer { capacity, .. } => Some(*capacity - self.written),
+            #[cfg(feature = "alloc")]
+            Target::BitVec(_) => None
+        }
+    }
+
+    /// Returns a bit slice over the bits that have been written.
+    #[inline]
+    pub fn written(&self) -> BitSlice<'_, O> {
+        match (&self.target) {
+            Target::Buffer { ptr, .. } => unsafe { BitSlice::from_raw_parts(ptr.as_const(), self.written) },
+            #[cfg(feature = "alloc")]
+            Target::BitVec(bitvec) => bitvec.as_bitslice().split_at(bitvec.len() - self.written).1
+        }
+    }
+
+    /// Makes room for `bit_count` more bits, and returns a pointer to the first of them.
+    ///
+    /// The bits are counted as written.
+    fn claim(&mut self, bit_count : usize) -> Result<BitPtrMut<O>, BufferFull> {
+        let ptr = match (&mut self.target) {
+            Target::Buffer { ptr, capacity, .. } => {
+                let remaining = *capacity - self.written;
+                if (bit_count > remaining) {
+                    return Err(BufferFull { requested : bit_count, remaining });
+                }
+                unsafe { ptr.bit_add(self.written) }
+            },
+            #[cfg(feature = "alloc")]
+            Target::BitVec(bitvec) => {
+                let start = bitvec.len();
+                bitvec.resize(start.checked_add(bit_count).expect("capacity overflow"), false);
+                unsafe { bitvec.as_bitptr_mut().bit_add(start) }
+            }
+        };
+        self.written += bit_count;
+        Ok(ptr)
+    }
+
+    /// Writes a bit.
+    pub fn write_bool(&mut self, value : bool) -> Result<(), BufferFull> {
+        let ptr = self.claim(1)?;
+        unsafe { ptr.write(value); }
+        Ok(())
+    }
+
+    /// Writes the lowest `width` bits of `value`.
+    ///
+    /// See [`BitPtrMut::write_bits`].
+    ///
+    /// # Panics
+    /// Panics if `width` is greater than `T::BITS`.
+    pub fn write_bits<T : Unsigned>(&mut self, value : T, width : u32) -> Result<(), BufferFull> {
+        assert!(width <= T::BITS, "width is greater than the size of the integer type");
+        let ptr = self.claim(width as usize)?;
+        unsafe { ptr.write_bits(value, width); }
+        Ok(())
+    }
+
+    /// Writes the lowest `width` bits of the two's complement representation of `value`.
+    ///
+    /// See [`BitPtrMut::write_bits_signed`].
+    ///
+    /// # Panics
+    /// Panics if `width` is greater than `T::BITS`.
+    pub fn write_bits_signed<T : Signed>(&mut self, value : T, width : u32) -> Result<(), BufferFull> {
+        assert!(width <= T::BITS, "width is greater than the size of the integer type");
+        let ptr = self.claim(width as usize)?;
+        unsafe { ptr.write_bits_signed(value, width); }
+        Ok(())
+    }
+
+    /// Writes all bits of `bits`.
+    ///
+    /// `bits` may use a different [bit order](BitOrder).
+    pub fn write_bitslice<P : BitOrder>(&mut self, bits : &BitSlice<'_, P>) -> Result<(), BufferFull> {
+        let ptr = self.claim(bits.len())?;
+        unsafe { crate::copy_nonoverlapping(bits.as_bitptr(), ptr, bits.len()); }
+        Ok(())
+    }
+
+    /// Writes all bits of `bytes`.
+    pub fn write_bytes(&mut self, bytes : &[u8]) -> Result<(), BufferFull> {
+        self.write_bitslice(&BitSlice::new(bytes).cast_order::<O>())
+    }
+
+    /// Writes `value` until the next bit to be written is at the start of a byte in memory.
+    ///
+    /// If the next bit is already at the start of a byte, this has no effect.
+    pub fn pad_to_byte(&mut self, value : bool) -> Result<(), BufferFull> {
+        let bit = match (&self.target) {
+            Target::Buffer { ptr, .. } => unsafe { ptr.bit_add(self.written) }.subbyte_bit().get(),
+            #[cfg(feature = "alloc")]
+            Target::BitVec(bitvec) => (bitvec.len() % 8) as u8
+        };
+        if (bit == 0) { return Ok(()); }
+        let bit_count = (8 - bit) as usize;
+        let ptr = self.claim(bit_count)?;
+        unsafe { crate::fill(ptr, bit_count, value); }
+        Ok(())
+    }
+
+}
+
+
+/// The error returned by a [`BitWriter`] when more bits were written than fit in its buffer.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct BufferFull {
+    requested : usize,
+    remaining : usize
+}
+
+impl BufferFull {
+
+    /// Returns the number of bits that were requested to be written.
+    #[inline(always)]
+    pub const fn requested(&self) -> usize { self.requested }
+
+    /// Returns the number of bits that could still be written.
+    #[inline(always)]
+    pub const fn remaining(&self) -> usize { self.remaining }
+
+}
+
+impl fmt::Display for BufferFull {
+    fn fmt(&self, f : &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "buffer full: requested {} bits, but only {} remain", self.requested, self.remaining)
+    }
+}
+
+impl core::error::Error for BufferFull { }
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Lsb0;
+
+
+    #[test]
+    fn writer_write_fields() {
+        let mut x = [0b10101010u8; 3];
+
+        let mut w = BitWriter::from_bitslice(BitSliceMut::new_range(&mut x, 2..23).unwrap());
+        assert_eq!(w.remaining(), Some(21));
+        w.write_bits::<u8>(0b0110, 4).unwrap();
+        w.write_bool(false).unwrap();
+        w.pad_to_byte(true).unwrap();
+        assert_eq!(w.bits_written(), 6);
+        w.pad_to_byte(false).unwrap();
+        assert_eq!(w.bits_written(), 6);
+        w.write_bits_signed::<i16>(-2, 9).unwrap();
+        assert_eq!(w.write_bits::<u8>(0, 7), Err(BufferFull { requested : 7, remaining : 6 }));
+        w.write_bits::<u8>(0b000111, 6).unwrap();
+        assert_eq!(w.remaining(), Some(0));
+        assert_eq!(w.write_bool(true), Err(BufferFull { requested : 1, remaining : 0 }));
+        assert_eq!(w.pad_to_byte(true), Err(BufferFull { requested : 1, remaining : 0 }));
+        assert_eq!(w.written().len(), 21);
+        assert_eq!(x, [0b10011001u8, 0b11111111, 0b00001110]);
+    }
+
+
+    #[test]
+    fn writer_write_bitslice() {
+        let     x = [0b01011011u8, 0b10010110];
+        let mut y = [0u8; 2];
+
+        let mut w = BitWriter::new(&mut y).cast_order::<Lsb0>().ok().unwrap();
+        w.write_bits::<u8>(0b101, 3).unwrap();
+        w.write_bitslice(&BitSlice::new_range(&x, 7..14).unwrap()).unwrap();
+        assert_eq!(w.write_bytes(&x), Err(BufferFull { requested : 16, remaining : 6 }));
+        w.pad_to_byte(false).unwrap();
+        assert_eq!(y, [0b10011101u8, 0b00000010]);
+    }
+
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn writer_bitvec() {
+        let mut x = BitVec::new();
+        x.push(true);
+
+        let mut w = BitWriter::from_bitvec(&mut x);
+        assert_eq!(w.remaining(), None);
+        w.write_bits::<u16>(0b1100101, 7).unwrap();
+        w.pad_to_byte(true).unwrap();
+        w.write_bytes(&[0b01011011, 0b10010110]).unwrap();
+        w.write_bool(true).unwrap();
+        assert_eq!(w.bits_written(), 24);
+        assert_eq!(w.written().len(), 24);
+        assert!(w.cast_order::<Lsb0>().is_err());
+        assert_eq!(x.len(), 25);
+        assert_eq!(x.as_bytes(), [0b11100101u8, 0b01011011, 0b10010110, 0b10000000]);
+    }
+
+}